@@ -47,7 +47,7 @@ impl EventFilter {
         use octotablet::events::{Event, PadEvent, PadGroupEvent, ToolEvent, TouchStripEvent};
         match event {
             Event::Tool { event, .. } => match event {
-                ToolEvent::Pose(..) => self.poses,
+                ToolEvent::Pose(..) | ToolEvent::PredictedPose(..) => self.poses,
                 ToolEvent::Frame(..) => self.frames,
                 _ => true,
             },
@@ -186,7 +186,7 @@ pub fn format_event(event: Event) -> (String, eframe::egui::Color32) {
                         PadGroupEvent::Mode(m) => {
                             (format!("{name} switched to mode {m}"), colors::MODE)
                         }
-                        PadGroupEvent::Ring { ring, event } => {
+                        PadGroupEvent::Ring { ring, event, .. } => {
                             let ring = ring.id();
                             format_touch_event(format!("Ring {ring:08X?}"), event, |pose| {
                                 format!("{:.01}deg", pose.to_degrees())
@@ -215,6 +215,9 @@ pub fn format_event(event: Event) -> (String, eframe::egui::Color32) {
                 ToolEvent::Down => (format!("{name} down"), colors::PRESSED),
                 ToolEvent::Up => (format!("{name} up"), colors::RELEASED),
                 ToolEvent::Pose(pose) => (format!("{name} {pose:#?}"), colors::POSE),
+                ToolEvent::PredictedPose(pose) => {
+                    (format!("{name} predicted {pose:#?}"), colors::POSE)
+                }
                 ToolEvent::Button { button_id, pressed } => (
                     format!(
                         "{name} button {button_id:08X?} {}",
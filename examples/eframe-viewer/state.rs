@@ -19,25 +19,6 @@ struct ToolState {
     down: bool,
 }
 
-/// Fetch the difference between two angles in `[0, TAU)`
-/// This requires extra logic to find the delta when the state crosses the modulous point.
-fn radial_delta(from: f32, to: f32) -> f32 {
-    use std::f32::consts::{PI, TAU};
-    // Check the actual `to` position, as well as the next closest one to `from`
-    let rotations = [to, if from > PI { to + TAU } else { to - TAU }];
-
-    // Find variation with smallest absolute delta.
-    // This is to handle the continuity break from jumping from the end of one rotation
-    // to the start of the next.
-    let nearest_delta = rotations
-        .iter()
-        .map(|rot| from - rot)
-        .min_by(|&a, &b| (a.abs()).total_cmp(&b.abs()));
-
-    // Unwrap OK - this array is always non-empty of course!
-    nearest_delta.unwrap()
-}
-
 #[derive(Default)]
 pub struct State {
     /// State of any `In` tools, removed when they go `Out`.
@@ -97,11 +78,14 @@ impl<'a> Extend<octotablet::events::Event<'a>> for State {
                     ToolEvent::Removed | ToolEvent::Out => {
                         self.tools.remove(&tool.id());
                     }
-                    ToolEvent::Added | ToolEvent::Button { .. } | ToolEvent::Frame(..) => (),
+                    ToolEvent::Added
+                    | ToolEvent::Button { .. }
+                    | ToolEvent::PredictedPose(..)
+                    | ToolEvent::Frame(..) => (),
                 },
                 Event::Pad { pad, event } => match event {
                     PadEvent::Group { event, .. } => match event {
-                        PadGroupEvent::Ring { ring, event } => match event {
+                        PadGroupEvent::Ring { ring, event, .. } => match event {
                             TouchStripEvent::Up => {
                                 // End interaction by deleting the state
                                 self.rings.remove(&ring.id());
@@ -109,10 +93,8 @@ impl<'a> Extend<octotablet::events::Event<'a>> for State {
                             TouchStripEvent::Pose(p) => match self.rings.entry(ring.id()) {
                                 collections::hash_map::Entry::Occupied(mut o) => {
                                     // Continued interaction, find the delta and advance the knob
-                                    let delta = radial_delta(*o.get(), p);
-                                    // Negative converts from delta clockwise as reported from rings
-                                    // to delta counterclockwise
-                                    self.knob_pos -= delta;
+                                    let delta = pad::ring::radial_delta(*o.get(), p);
+                                    self.knob_pos += delta;
                                     // Ensure it remains in [0, TAU)
                                     self.knob_pos %= std::f32::consts::TAU;
                                     o.insert(p);
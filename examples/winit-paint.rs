@@ -150,10 +150,7 @@ impl Painter {
                         // Create a painter.
                         self.tools.entry(tool.id()).or_insert_with(|| ToolPainter {
                             // Mark eraser-type tools to erase
-                            is_eraser: matches!(
-                                tool.tool_type,
-                                Some(octotablet::tool::Type::Eraser)
-                            ),
+                            is_eraser: tool.is_eraser(),
                             ..Default::default()
                         });
                     }
@@ -59,7 +59,7 @@ pub enum Axis {
     Tilt,
     /// The tool can sense a distance from the pad.
     Distance,
-    /// The tool can sense absolute roll angle around its own axis.
+    /// The tool can sense absolute roll angle around its own axis, also known as barrel rotation or twist.
     Roll,
     /// The tool has a scroll wheel. It may report continuous motion as well as discrete steps.
     Wheel,
@@ -104,6 +104,43 @@ impl<U: Union + Clone> Union for Option<U> {
     }
 }
 
+/// The unit of [`Pose::tilt`] and the [limits](FullInfo::tilt) of the tilt axis.
+/// See [`Builder::tilt_unit`](crate::Builder::tilt_unit).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AngleUnit {
+    /// Radians, as used throughout the rest of the crate.
+    #[default]
+    Radians,
+    /// Degrees, for applications that work in degrees natively.
+    Degrees,
+}
+impl AngleUnit {
+    /// Convert an angle in radians into this unit.
+    pub(crate) fn convert_radians(self, radians: f32) -> f32 {
+        match self {
+            Self::Radians => radians,
+            Self::Degrees => radians.to_degrees(),
+        }
+    }
+    /// Convert an angle in degrees into this unit.
+    pub(crate) fn convert_degrees(self, degrees: f32) -> f32 {
+        match self {
+            Self::Radians => degrees.to_radians(),
+            Self::Degrees => degrees,
+        }
+    }
+    /// Convert the limits of an axis in radians into this unit.
+    pub(crate) fn convert_radians_info(self, info: Info) -> Info {
+        Info {
+            limits: info.limits.map(|limits| Limits {
+                min: self.convert_radians(limits.min),
+                max: self.convert_radians(limits.max),
+            }),
+            ..info
+        }
+    }
+}
+
 /// Describes the number of unique values in the entire range of the associated axis.
 ///
 /// This does not affect the range of values nor the interpretation of values reported by a [`Pose`].
@@ -143,6 +180,19 @@ pub struct Limits {
     /// Inclusive maximum
     pub max: f32,
 }
+impl Limits {
+    /// Convert limits of an angular axis, such as [`FullInfo::tilt`], from radians to degrees for display.
+    ///
+    /// This assumes the limits are in radians, as they are with the default [`Builder::tilt_unit`](crate::Builder::tilt_unit).
+    /// With [`AngleUnit::Degrees`], tilt limits are already in degrees and must not be converted again.
+    #[must_use]
+    pub fn to_degrees(self) -> Limits {
+        Limits {
+            min: self.min.to_degrees(),
+            max: self.max.to_degrees(),
+        }
+    }
+}
 impl From<Limits> for std::ops::RangeInclusive<f32> {
     fn from(value: Limits) -> std::ops::RangeInclusive<f32> {
         value.min..=value.max
@@ -168,11 +218,18 @@ impl Union for Limits {
 #[derive(Clone, Copy, Debug, Default)]
 pub struct NormalizedInfo {
     pub granularity: Option<Granularity>,
+    /// The number of discrete levels the hardware natively senses, such as `8192` for many modern pens' pressure.
+    ///
+    /// Only reported when the hardware's raw integer range is a plausible level count (a power of two), and `None`
+    /// for continuous or unknown ranges. Unlike [`NormalizedInfo::granularity`], this is suitable for display in
+    /// calibration UIs.
+    pub native_levels: Option<u32>,
 }
 impl Union for NormalizedInfo {
     fn union(&self, other: &Self) -> Self {
         Self {
             granularity: self.granularity.union(&other.granularity),
+            native_levels: self.native_levels.max(other.native_levels),
         }
     }
 }
@@ -207,9 +264,7 @@ impl Union for LengthInfo {
     fn union(&self, other: &Self) -> Self {
         match (self, other) {
             (Self::Centimeters(a), Self::Centimeters(b)) => Self::Centimeters(a.union(b)),
-            (Self::Normalized(a), Self::Normalized(b)) => Self::Normalized(NormalizedInfo {
-                granularity: a.granularity.max(b.granularity),
-            }),
+            (Self::Normalized(a), Self::Normalized(b)) => Self::Normalized(a.union(b)),
             (Self::Centimeters(cm), Self::Normalized(n))
             | (Self::Normalized(n), Self::Centimeters(cm)) => {
                 // Uh oh.. This occurs when a linear axis and it's sibling disagree on whether the units are normalized or not.
@@ -217,6 +272,7 @@ impl Union for LengthInfo {
                 // FIXME!
                 Self::Normalized(NormalizedInfo {
                     granularity: n.granularity.max(cm.granularity),
+                    native_levels: n.native_levels,
                 })
             }
         }
@@ -292,6 +348,10 @@ pub struct FullInfo {
     pub roll: Option<CircularInfo>,
     pub pressure: Option<NormalizedInfo>,
     pub button_pressure: Option<NormalizedInfo>,
+    /// `None` if tilt is not supported. `Some` with [`Info::limits`] of `None` means tilt is supported,
+    /// but its range is unknown. See [`FullInfo::supports_tilt`].
+    ///
+    /// Limits are in radians, or the unit configured by [`Builder::tilt_unit`](crate::Builder::tilt_unit).
     pub tilt: Option<Info>,
     pub wheel: Option<CircularInfo>,
     pub distance: Option<LengthInfo>,
@@ -338,6 +398,11 @@ impl FullInfo {
         | self.distance.map_or(empty, |_| AvailableAxes::DISTANCE)
         | self.contact_size.map_or(empty, |_| AvailableAxes::CONTACT_SIZE)
     }
+    /// Query whether tilt is reported, regardless of whether its range is known.
+    #[must_use]
+    pub fn supports_tilt(&self) -> bool {
+        self.tilt.is_some()
+    }
     /// Query the granularity of an axis. For all axis supported by this function,
     /// the granularity is the total number of states between the minimum and maximum value of the axis.
     /// # Errors
@@ -448,6 +513,9 @@ pub struct Pose {
     /// correct interpretation of these values.
     ///
     /// This may have sub-pixel precision, and may exceed your window size in the negative or positive directions.
+    ///
+    /// No window geometry is cached to compute this, so positions stay correct across a resize, even mid-stroke.
+    /// Changes in DPI are picked up as they happen, too.
     pub position: [f32; 2],
     /// Perpendicular distance from the surface of the tablet. This may be an arbitrary, unitless `[0, 1]` value, or
     /// reported in physical centimeters, see the [`FullInfo::distance`] of the reporting [`Tool`](crate::tool::Tool) for interpretation.
@@ -469,11 +537,16 @@ pub struct Pose {
     /// and the second angle describes the angle between the pen and Z on the YZ plane.
     ///
     /// `[+,+]` is right+towards user, and `[-,-]` is left+away from user.
+    ///
+    /// Reported in degrees instead if configured by [`Builder::tilt_unit`](crate::Builder::tilt_unit).
     /// # Quirks
     /// In theory the vector `[sin x, sin y]` should describe a projection of the pen's body down on the page, with length <= 1.
     /// However in practice, reported values may break this trigonometric invariant.
     pub tilt: Option<[f32; 2]>,
-    /// Absolute roll in radians, around the tool's long axis. Zero is a hardware-determined "natural" angle.
+    /// Absolute barrel rotation in radians, around the tool's long axis, increasing clockwise when viewed from above
+    /// the tablet. Zero is a hardware-determined "natural" angle, typically with the pen's reference mark facing up.
+    ///
+    /// This is measured by the pen itself (e.g. an art pen's rotation sensor) and is *not* derived from [`Pose::tilt`].
     pub roll: NicheF32,
     /// Absolute scroll wheel angle and clicks in radians, unspecified range or zero-point.
     /// Note that the clicks are *not* a delta.
@@ -484,3 +557,41 @@ pub struct Pose {
     /// and second describes the Y-axis height. See [`FullInfo::contact_size`] of the reporting [`Tool`](crate::tool::Tool) for units.
     pub contact_size: Option<[f32; 2]>,
 }
+impl Pose {
+    /// The set of axes this pose carries a value for. Position is implicit and always present.
+    ///
+    /// This is usually, but not necessarily, the same as the [`FullInfo::available`] of the reporting
+    /// [`Tool`](crate::tool::Tool) - an available axis may be missing from individual poses.
+    #[must_use]
+    pub fn present_axes(&self) -> AvailableAxes {
+        let mut axes = AvailableAxes::empty();
+        axes.set(AvailableAxes::PRESSURE, self.pressure.get().is_some());
+        axes.set(AvailableAxes::TILT, self.tilt.is_some());
+        axes.set(AvailableAxes::DISTANCE, self.distance.get().is_some());
+        axes.set(AvailableAxes::ROLL, self.roll.get().is_some());
+        axes.set(AvailableAxes::WHEEL, self.wheel.is_some());
+        axes.set(AvailableAxes::SLIDER, self.slider.get().is_some());
+        axes.set(
+            AvailableAxes::BUTTON_PRESSURE,
+            self.button_pressure.get().is_some(),
+        );
+        axes.set(AvailableAxes::CONTACT_SIZE, self.contact_size.is_some());
+        axes
+    }
+    /// Round [`Pose::position`] to the nearest whole logical pixel, for hit-testing against integer rectangles.
+    ///
+    /// Halves round up (towards positive infinity), such that a pixel `n` covers the positions `[n - 0.5, n + 0.5)`.
+    /// `None` if the position is not finite or does not fit in an `i32`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub fn window_pixel(&self) -> Option<(i32, i32)> {
+        let round = |v: f32| {
+            // Not `(v + 0.5).floor()`, as that sum itself may round up, e.g. for `0.49999997`.
+            let floor = v.floor();
+            let v = if v - floor >= 0.5 { floor + 1.0 } else { floor };
+            // `as` saturates, so check the range first. Exclusive max, as `i32::MAX as f32` rounds up to 2^31.
+            (v >= i32::MIN as f32 && v < i32::MAX as f32).then_some(v as i32)
+        };
+        Some((round(self.position[0])?, round(self.position[1])?))
+    }
+}
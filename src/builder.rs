@@ -26,11 +26,21 @@ impl From<raw_window_handle::HandleError> for BuildError {
 #[allow(unused)]
 pub struct Builder {
     pub(crate) emulate_tool_from_mouse: bool,
+    pub(crate) predict_motion: Option<std::time::Duration>,
+    pub(crate) event_history: usize,
+    pub(crate) synthesize_binary_pressure: bool,
+    pub(crate) tilt_unit: crate::axis::AngleUnit,
+    pub(crate) pose_on_button: bool,
 }
 impl Default for Builder {
     fn default() -> Self {
         Self {
             emulate_tool_from_mouse: true,
+            predict_motion: None,
+            event_history: 0,
+            synthesize_binary_pressure: false,
+            tilt_unit: crate::axis::AngleUnit::Radians,
+            pose_on_button: false,
         }
     }
 }
@@ -56,6 +66,79 @@ impl Builder {
             ..self
         }
     }
+    /// Enable motion prediction, emitting a [`PredictedPose`](crate::events::ToolEvent::PredictedPose) the given duration
+    /// ahead of each measured pose, linearly extrapolated from the last two poses' position and pressure. Prediction
+    /// restarts from scratch after each `Out`.
+    ///
+    /// Useful for hiding latency when drawing, at the cost of overshoot on sudden stops and turns.
+    ///
+    /// Defaults to disabled.
+    ///
+    /// # Supprted platforms
+    /// * Wayland
+    /// * Windows Ink, on devices that report timestamps
+    #[must_use]
+    pub fn predict_motion(self, ahead: std::time::Duration) -> Self {
+        Self {
+            predict_motion: Some(ahead),
+            ..self
+        }
+    }
+    /// Retain up to this many of the most recent events across pumps, for diagnostics such as crash reports.
+    /// See [`Manager::recent_events`].
+    ///
+    /// Defaults to `0`, disabled.
+    #[must_use]
+    pub fn event_history(self, len: usize) -> Self {
+        Self {
+            event_history: len,
+            ..self
+        }
+    }
+    /// Set whether tools without a pressure axis should report a binary pressure in their poses - `1.0` while
+    /// [`Down`](crate::events::ToolEvent::Down), `0.0` otherwise - so that pressure-driven apps still work on
+    /// pressureless hardware. Tools that report pressure are unaffected.
+    ///
+    /// The tool's [`FullInfo::pressure`](crate::axis::FullInfo::pressure) is still reported as `None`.
+    ///
+    /// Defaults to `false`.
+    #[must_use]
+    pub fn synthesize_binary_pressure(self, synthesize: bool) -> Self {
+        Self {
+            synthesize_binary_pressure: synthesize,
+            ..self
+        }
+    }
+    /// Set the unit of [`Pose::tilt`](crate::axis::Pose::tilt), for applications that work in degrees natively.
+    /// The [tilt limits](crate::axis::FullInfo::tilt) of every tool are reported in the same unit, so they remain
+    /// comparable with the live values.
+    ///
+    /// Defaults to [`AngleUnit::Radians`](crate::axis::AngleUnit::Radians).
+    #[must_use]
+    pub fn tilt_unit(self, unit: crate::axis::AngleUnit) -> Self {
+        Self {
+            tilt_unit: unit,
+            ..self
+        }
+    }
+    /// Set whether tool [`Button`](crate::events::ToolEvent::Button) events should always share a frame with a
+    /// [`Pose`](crate::events::ToolEvent::Pose), repeating the tool's last known pose if needed. This makes "click at
+    /// position" self-contained, without remembering the last motion.
+    ///
+    /// Wayland already repeats the last pose in every frame, regardless of this setting. On either platform, button events
+    /// before the tool's first pose remain without one.
+    ///
+    /// # Supprted platforms
+    /// * Windows Ink
+    ///
+    /// Defaults to `false`.
+    #[must_use]
+    pub fn pose_on_button(self, pose: bool) -> Self {
+        Self {
+            pose_on_button: pose,
+            ..self
+        }
+    }
 }
 /// # Finishing
 impl Builder {
@@ -105,6 +188,8 @@ impl Builder {
         rwh: impl raw_window_handle::HasDisplayHandle + raw_window_handle::HasWindowHandle,
         backing: Backing,
     ) -> Result<Manager, BuildError> {
+        // Backends consume the builder, grab what the `Manager` needs first.
+        let history_len = self.event_history;
         let internal = match rwh.display_handle()?.as_raw() {
             #[cfg(wl_tablet)]
             raw_window_handle::RawDisplayHandle::Wayland(wlh) => {
@@ -140,6 +225,13 @@ impl Builder {
 
         Ok(Manager {
             internal,
+            events_received: 0,
+            // Grows as events arrive, rather than trusting a potentially huge `history_len` up front.
+            history: std::collections::VecDeque::new(),
+            history_len,
+            tool_states: Vec::new(),
+            last_event_time: None,
+            closing_events: None,
             _backing: backing,
         })
     }
@@ -0,0 +1,128 @@
+//! # `egui` integration
+//!
+//! Translates tablet [events](crate::events) into [`egui`](https://crates.io/crates/egui) pointer input, so that
+//! tablet tools can drive an `egui` UI without any per-app glue. Requires the `egui` feature.
+//!
+//! Positions are passed through as-is, assuming one `egui` point is one logical pixel of the window - that is,
+//! an `egui` zoom factor of `1.0`.
+//!
+//! ```no_run
+//! # fn frame(manager: &mut octotablet::Manager, raw_input: &mut egui::RawInput) {
+//! # let mut translator = octotablet::egui::Translator::new();
+//! let events = manager.pump().unwrap();
+//! for event in events {
+//!     translator.push(event, raw_input);
+//! }
+//! # }
+//! ```
+
+use crate::{events::Event, events::ToolEvent, tool};
+
+/// Stateful converter from tablet events to `egui` events. Keep one of these alive for the lifetime of the
+/// [`Manager`](crate::Manager), and feed it every event.
+///
+/// Each tool emits:
+/// * [`egui::Event::PointerMoved`] on every pose,
+/// * [`egui::Event::PointerButton`] with the primary button on [`Down`](ToolEvent::Down) and [`Up`](ToolEvent::Up),
+/// * [`egui::Event::PointerGone`] on [`Out`](ToolEvent::Out),
+/// * [`egui::Event::Touch`] while down, carrying the tool's pressure as the touch `force`.
+///
+/// Tool buttons are opaque and have no meaningful `egui` equivalent, so they are not translated.
+#[derive(Debug, Default)]
+pub struct Translator {
+    /// Last known position and down state of each tool, as `egui` needs a position for button events
+    /// while tablet button events carry none.
+    tools: std::collections::HashMap<tool::ID, ToolState>,
+}
+#[derive(Debug, Default, Clone, Copy)]
+struct ToolState {
+    pos: Option<::egui::Pos2>,
+    down: bool,
+}
+impl Translator {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Translate a single event, appending the result (if any) to `raw_input`'s events.
+    pub fn push(&mut self, event: Event<'_>, raw_input: &mut ::egui::RawInput) {
+        let Event::Tool { tool, event } = event else {
+            // Pads and tablets have no pointer equivalent.
+            return;
+        };
+        let id = tool.id();
+        let touch_id = touch_id(&id);
+        let modifiers = raw_input.modifiers;
+        let events = &mut raw_input.events;
+        match event {
+            ToolEvent::Pose(pose) => {
+                let pos = ::egui::pos2(pose.position[0], pose.position[1]);
+                let state = self.tools.entry(id).or_default();
+                state.pos = Some(pos);
+                events.push(::egui::Event::PointerMoved(pos));
+                if state.down {
+                    events.push(::egui::Event::Touch {
+                        device_id: ::egui::TouchDeviceId(touch_id),
+                        id: ::egui::TouchId(touch_id),
+                        phase: ::egui::TouchPhase::Move,
+                        pos,
+                        force: pose.pressure.get(),
+                    });
+                }
+            }
+            ToolEvent::Down | ToolEvent::Up => {
+                let pressed = matches!(event, ToolEvent::Down);
+                let state = self.tools.entry(id).or_default();
+                state.down = pressed;
+                // Without a position there is nowhere to click. Poses precede `Down` in practice.
+                let Some(pos) = state.pos else {
+                    return;
+                };
+                events.push(::egui::Event::PointerButton {
+                    pos,
+                    button: ::egui::PointerButton::Primary,
+                    pressed,
+                    modifiers,
+                });
+                events.push(::egui::Event::Touch {
+                    device_id: ::egui::TouchDeviceId(touch_id),
+                    id: ::egui::TouchId(touch_id),
+                    phase: if pressed {
+                        ::egui::TouchPhase::Start
+                    } else {
+                        ::egui::TouchPhase::End
+                    },
+                    pos,
+                    force: None,
+                });
+            }
+            ToolEvent::Out | ToolEvent::Removed => {
+                if let Some(state) = self.tools.remove(&id) {
+                    if let (true, Some(pos)) = (state.down, state.pos) {
+                        // Left while still pressed - don't leave egui with a dangling touch.
+                        events.push(::egui::Event::Touch {
+                            device_id: ::egui::TouchDeviceId(touch_id),
+                            id: ::egui::TouchId(touch_id),
+                            phase: ::egui::TouchPhase::Cancel,
+                            pos,
+                            force: None,
+                        });
+                    }
+                    events.push(::egui::Event::PointerGone);
+                }
+            }
+            ToolEvent::Added
+            | ToolEvent::In { .. }
+            | ToolEvent::Button { .. }
+            | ToolEvent::PredictedPose(_)
+            | ToolEvent::Frame(_) => (),
+        }
+    }
+}
+/// Derive a stable numeric touch ID from the opaque tool ID.
+fn touch_id(id: &tool::ID) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    id.hash(&mut hasher);
+    hasher.finish()
+}
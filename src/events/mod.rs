@@ -9,6 +9,10 @@ use crate::{axis::Pose, pad, platform::PlatformImpl, tablet::Tablet, tool::Tool,
 ///
 /// Subtract two timestamps to get the duration between them, with [`FrameTimestamp::epoch`]
 /// being the somewhat-meaningless starting point.
+///
+/// Timestamps from all tools of a single [`Manager`] share the same clock, and thus may be ordered against each other
+/// to interleave events from several tools. The [`Ord`] impl compares the raw clock value and is not aware of wraparound,
+/// see [`FrameTimestamp::duration_since`].
 #[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct FrameTimestamp(pub(crate) std::time::Duration);
 impl FrameTimestamp {
@@ -20,6 +24,19 @@ impl FrameTimestamp {
     pub fn epoch() -> Self {
         Self(std::time::Duration::ZERO)
     }
+    /// Get the time elapsed from `earlier` to `self`, accounting for the server clock wrapping around.
+    ///
+    /// Servers report time as a 32-bit count of milliseconds which wraps back to zero roughly every 49.7 days.
+    /// If `earlier` is numerically larger than `self`, the clock is assumed to have wrapped exactly once between the two.
+    /// This is unlike the `-` operator, which panics in that case.
+    #[must_use]
+    pub fn duration_since(self, earlier: Self) -> std::time::Duration {
+        // Period of a 32-bit millisecond clock.
+        const WRAP: std::time::Duration = std::time::Duration::from_millis(1 << 32);
+        self.0
+            .checked_sub(earlier.0)
+            .unwrap_or_else(|| (self.0 + WRAP).saturating_sub(earlier.0))
+    }
 }
 impl std::ops::Sub for FrameTimestamp {
     type Output = std::time::Duration;
@@ -59,12 +76,17 @@ pub enum ToolEvent<'a> {
     /// The tool may immediately be added again before next use, use its [hardware id](crate::tool::Tool::hardware_id)
     /// to re-associate it with its past self.
     ///
+    /// The accompanying [`Tool`] is still fully populated, and remains in [`Manager::tools`] until the next
+    /// [`pump`](Manager::pump), so its final description can be inspected here.
+    ///
     /// This is not part of a `Frame`.
     Removed,
     /// The tool has entered sensing range or entered the window region over the given `tablet`.
     /// Note that this is subject to filtering by the OS -
     /// you may or may not recieve this event when the pen enters sensing range
     /// above a different window.
+    ///
+    /// `tablet` is always a member of [`Manager::tablets`] at the time the event is observed.
     In { tablet: &'a Tablet },
     /// The tool is considered "pressed." It is implementation defined what the exact semantics are,
     /// but you should treat this as a click or command to start drawing.
@@ -81,6 +103,12 @@ pub enum ToolEvent<'a> {
     // This single variant is so much larger than all the others and inflates the whole
     // event enum by over 2x D:
     Pose(Pose),
+    /// An estimate of where the tool will be a short time after the [`Pose`](ToolEvent::Pose) of the same frame,
+    /// extrapolated from recent motion. Only sent when enabled by [`Builder::predict_motion`](crate::Builder::predict_motion).
+    ///
+    /// This is *not* a measurement - it should be drawn tentatively and replaced by the next frame's real pose, and never
+    /// committed to a stroke.
+    PredictedPose(Pose),
     /// The preceding events are submitted as a group, at the given time.
     Frame(Option<FrameTimestamp>),
     /// The tool is no longer pressed.
@@ -95,6 +123,9 @@ pub enum TabletEvent {
     /// may be newly plugged in, or sent immediately before its first use.
     Added,
     /// Unplugged or otherwise becomes unavailable. The tablet will be removed from the hardware report.
+    ///
+    /// The accompanying [`Tablet`] is still fully populated, and remains in [`Manager::tablets`] until the next
+    /// [`pump`](Manager::pump), so its final description can be inspected here.
     Removed,
 }
 /// Events associated with a specific [`Pad`](pad::Pad).
@@ -104,6 +135,9 @@ pub enum PadEvent<'a> {
     /// may be newly plugged in, or sent immediately before its first use.
     Added,
     /// Unplugged or otherwise becomes unavailable. The pad will be removed from the hardware report.
+    ///
+    /// The accompanying [`Pad`](pad::Pad) is still fully populated, and remains in [`Manager::pads`] until the next
+    /// [`pump`](Manager::pump), so its final description can be inspected here.
     Removed,
     /// Group-specific events
     Group {
@@ -131,6 +165,11 @@ pub enum PadGroupEvent<'a> {
     /// A ring was interacted.
     Ring {
         ring: &'a pad::Ring,
+        /// The [mode](pad::Group::current_mode) of the owning group at the time of this event, if known.
+        ///
+        /// On hardware where a mode button cycles a single physical ring through several functions (such as the
+        /// *Wacom Intuos Pro*), this tells which logical function the ring is driving.
+        mode: Option<u32>,
         /// Contains the absolute angle, when changed.
         event: TouchStripEvent,
     },
@@ -206,7 +245,7 @@ impl<'manager> IntoIterator for Events<'manager> {
     fn into_iter(self) -> Self::IntoIter {
         EventIterator {
             manager: self.manager,
-            raw: self.manager.internal.raw_events(),
+            raw: self.manager.raw_events(),
         }
     }
 }
@@ -221,147 +260,173 @@ impl<'manager> EventIterator<'manager> {
         self.manager
     }
     // Get the next, or Err to retry.
-    #[allow(clippy::too_many_lines)]
     fn try_next(&mut self) -> Result<Option<<Self as Iterator>::Item>, ()> {
-        use raw::{
-            Event as RawEvent, PadEvent as RawPad, TabletEvent as RawTablet, ToolEvent as RawTool,
-        };
         let Some(next) = self.raw.next() else {
             return Ok(None);
         };
-        Ok(Some(match next {
-            RawEvent::Tool { tool, event } => {
-                // A linear scan is gonna be much more efficient than the alternatives
-                // for any reasonable number of tools. If you have like.... 30 tools at once, then
-                // maybe binary search would eek out a win :P
-                let tool = self
-                    .manager
-                    .tools()
-                    .iter()
-                    .find(|t| t.internal_id == tool)
-                    // Fail out (essentially a `filter` for invalid commands...)
-                    .ok_or(())?;
-                Event::Tool {
-                    tool,
-                    event: match event {
-                        RawTool::Added => ToolEvent::Added,
-                        RawTool::Removed => ToolEvent::Removed,
-                        RawTool::In { tablet } => ToolEvent::In {
-                            tablet: self
-                                .manager
-                                .tablets()
-                                .iter()
-                                .find(|t| t.internal_id == tablet)
-                                .unwrap(),
-                        },
-                        RawTool::Down => ToolEvent::Down,
-                        RawTool::Button { button_id, pressed } => ToolEvent::Button {
-                            button_id: crate::tool::ButtonID(button_id),
-                            pressed,
-                        },
-                        RawTool::Pose(v) => ToolEvent::Pose(v),
-                        RawTool::Frame(v) => ToolEvent::Frame(v),
-                        RawTool::Up => ToolEvent::Up,
-                        RawTool::Out => ToolEvent::Out,
+        resolve(self.manager, next).map(Some)
+    }
+}
+/// An event retained by [`Builder::event_history`](crate::Builder::event_history), see [`Manager::recent_events`].
+///
+/// Unlike [`Event`], this does not borrow the hardware it refers to, so it outlives that hardware being removed.
+/// The [`Debug`] output refers to hardware by the same opaque IDs as [`tool::ID`](crate::tool::ID) and friends,
+/// and is intended for crash reports and logs.
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct RecordedEvent(pub(crate) raw::Event<crate::platform::InternalID>);
+impl RecordedEvent {
+    /// Resolve into an [`Event`] referring to the manager's current hardware. `None` if any hardware it refers to
+    /// has since been removed - the [`Debug`] output remains available in that case.
+    #[must_use]
+    pub fn resolve<'manager>(&self, manager: &'manager Manager) -> Option<Event<'manager>> {
+        resolve(manager, self.0.clone()).ok()
+    }
+}
+impl std::fmt::Debug for RecordedEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+/// Resolve the IDs of a raw event into references to the manager's current hardware,
+/// or Err if any of them no longer exist.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn resolve(
+    manager: &Manager,
+    next: raw::Event<crate::platform::InternalID>,
+) -> Result<Event<'_>, ()> {
+    use raw::{
+        Event as RawEvent, PadEvent as RawPad, TabletEvent as RawTablet, ToolEvent as RawTool,
+    };
+    Ok(match next {
+        RawEvent::Tool { tool, event } => {
+            // A linear scan is gonna be much more efficient than the alternatives
+            // for any reasonable number of tools. If you have like.... 30 tools at once, then
+            // maybe binary search would eek out a win :P
+            let tool = manager
+                .tools()
+                .iter()
+                .find(|t| t.internal_id == tool)
+                // Fail out (essentially a `filter` for invalid commands...)
+                .ok_or(())?;
+            Event::Tool {
+                tool,
+                event: match event {
+                    RawTool::Added => ToolEvent::Added,
+                    RawTool::Removed => ToolEvent::Removed,
+                    RawTool::In { tablet } => ToolEvent::In {
+                        tablet: manager
+                            .tablets()
+                            .iter()
+                            .find(|t| t.internal_id == tablet)
+                            // Fail out (essentially a `filter` for invalid commands...)
+                            .ok_or(())?,
                     },
-                }
-            }
-            RawEvent::Tablet { tablet, event } => {
-                let tablet = self
-                    .manager
-                    .tablets()
-                    .iter()
-                    .find(|t| t.internal_id == tablet)
-                    // Fail out (essentially a `filter` for invalid commands...)
-                    .ok_or(())?;
-                Event::Tablet {
-                    tablet,
-                    event: match event {
-                        RawTablet::Added => TabletEvent::Added,
-                        RawTablet::Removed => TabletEvent::Removed,
+                    RawTool::Down => ToolEvent::Down,
+                    RawTool::Button { button_id, pressed } => ToolEvent::Button {
+                        button_id: crate::tool::ButtonID(button_id),
+                        pressed,
                     },
-                }
+                    RawTool::Pose(v) => ToolEvent::Pose(v),
+                    RawTool::PredictedPose(v) => ToolEvent::PredictedPose(v),
+                    RawTool::Frame(v) => ToolEvent::Frame(v),
+                    RawTool::Up => ToolEvent::Up,
+                    RawTool::Out => ToolEvent::Out,
+                },
             }
-            RawEvent::Pad { pad, event } => {
-                let pad = self
-                    .manager
-                    .pads()
-                    .iter()
-                    .find(|t| t.internal_id == pad)
-                    // Fail out (essentially a `filter` for invalid commands...)
-                    .ok_or(())?;
-                Event::Pad {
-                    pad,
-                    event: match event {
-                        RawPad::Added => PadEvent::Added,
-                        RawPad::Group { group, event } => {
-                            let group = pad
-                                .groups
-                                .iter()
-                                .find(|g| g.internal_id == group)
-                                // Fail out (essentially a `filter` for invalid commands...)
-                                .ok_or(())?;
-                            PadEvent::Group {
-                                group,
-                                event: match event {
-                                    raw::PadGroupEvent::Mode(m) => PadGroupEvent::Mode(m),
-                                    raw::PadGroupEvent::Ring { ring, event } => {
-                                        let ring = group
-                                            .rings
-                                            .iter()
-                                            .find(|r| r.internal_id == ring)
-                                            // Fail out (essentially a `filter` for invalid commands...)
-                                            .ok_or(())?;
-                                        PadGroupEvent::Ring { ring, event }
-                                    }
-                                    raw::PadGroupEvent::Strip { strip, event } => {
-                                        let strip = group
-                                            .strips
-                                            .iter()
-                                            .find(|s| s.internal_id == strip)
-                                            // Fail out (essentially a `filter` for invalid commands...)
-                                            .ok_or(())?;
-                                        PadGroupEvent::Strip { strip, event }
-                                    }
-                                },
-                            }
+        }
+        RawEvent::Tablet { tablet, event } => {
+            let tablet = manager
+                .tablets()
+                .iter()
+                .find(|t| t.internal_id == tablet)
+                // Fail out (essentially a `filter` for invalid commands...)
+                .ok_or(())?;
+            Event::Tablet {
+                tablet,
+                event: match event {
+                    RawTablet::Added => TabletEvent::Added,
+                    RawTablet::Removed => TabletEvent::Removed,
+                },
+            }
+        }
+        RawEvent::Pad { pad, event } => {
+            let pad = manager
+                .pads()
+                .iter()
+                .find(|t| t.internal_id == pad)
+                // Fail out (essentially a `filter` for invalid commands...)
+                .ok_or(())?;
+            Event::Pad {
+                pad,
+                event: match event {
+                    RawPad::Added => PadEvent::Added,
+                    RawPad::Group { group, event } => {
+                        let group = pad
+                            .groups
+                            .iter()
+                            .find(|g| g.internal_id == group)
+                            // Fail out (essentially a `filter` for invalid commands...)
+                            .ok_or(())?;
+                        PadEvent::Group {
+                            group,
+                            event: match event {
+                                raw::PadGroupEvent::Mode(m) => PadGroupEvent::Mode(m),
+                                raw::PadGroupEvent::Ring { ring, mode, event } => {
+                                    let ring = group
+                                        .rings
+                                        .iter()
+                                        .find(|r| r.internal_id == ring)
+                                        // Fail out (essentially a `filter` for invalid commands...)
+                                        .ok_or(())?;
+                                    PadGroupEvent::Ring { ring, mode, event }
+                                }
+                                raw::PadGroupEvent::Strip { strip, event } => {
+                                    let strip = group
+                                        .strips
+                                        .iter()
+                                        .find(|s| s.internal_id == strip)
+                                        // Fail out (essentially a `filter` for invalid commands...)
+                                        .ok_or(())?;
+                                    PadGroupEvent::Strip { strip, event }
+                                }
+                            },
                         }
-                        RawPad::Removed => PadEvent::Removed,
-                        RawPad::Button {
+                    }
+                    RawPad::Removed => PadEvent::Removed,
+                    RawPad::Button {
+                        button_idx,
+                        pressed,
+                    } => {
+                        // Find the group that owns this button, if any.
+                        // Not all buttons must be associated with a group!
+                        // Unsure of what hardware fits this description, if any...
+                        let group = pad.groups.iter().find(|group| {
+                            // Sorted, so we can use binary search owo
+                            // (tests show that binary search is somehow still more efficient than
+                            // linear scan even on trivially smol arrays hehe. this is pointless but fun)
+                            group.buttons.binary_search(&button_idx).is_ok()
+                        });
+                        PadEvent::Button {
                             button_idx,
                             pressed,
-                        } => {
-                            // Find the group that owns this button, if any.
-                            // Not all buttons must be associated with a group!
-                            // Unsure of what hardware fits this description, if any...
-                            let group = pad.groups.iter().find(|group| {
-                                // Sorted, so we can use binary search owo
-                                // (tests show that binary search is somehow still more efficient than
-                                // linear scan even on trivially smol arrays hehe. this is pointless but fun)
-                                group.buttons.binary_search(&button_idx).is_ok()
-                            });
-                            PadEvent::Button {
-                                button_idx,
-                                pressed,
-                                group,
-                            }
-                        }
-                        RawPad::Enter { tablet } => {
-                            let tablet = self
-                                .manager
-                                .tablets()
-                                .iter()
-                                .find(|t| t.internal_id == tablet)
-                                // Fail out (essentially a `filter` for invalid commands...)
-                                .ok_or(())?;
-                            PadEvent::Enter { tablet }
+                            group,
                         }
-                        RawPad::Exit => PadEvent::Exit,
-                    },
-                }
+                    }
+                    RawPad::Enter { tablet } => {
+                        let tablet = manager
+                            .tablets()
+                            .iter()
+                            .find(|t| t.internal_id == tablet)
+                            // Fail out (essentially a `filter` for invalid commands...)
+                            .ok_or(())?;
+                        PadEvent::Enter { tablet }
+                    }
+                    RawPad::Exit => PadEvent::Exit,
+                },
             }
-        }))
-    }
+        }
+    })
 }
 impl<'manager> Iterator for EventIterator<'manager> {
     type Item = Event<'manager>;
@@ -382,3 +447,28 @@ impl<'manager> Iterator for EventIterator<'manager> {
         maybe_next.unwrap()
     }
 }
+
+/// Group the [tool events](Event::Tool) of a pump by the tool that sent them, preserving their order within each tool.
+/// Pad and tablet events are discarded.
+///
+/// ```no_run
+/// # fn frame(manager: &mut octotablet::Manager) {
+/// let events = manager.pump().unwrap();
+/// for (tool, events) in octotablet::events::by_tool(events) {
+///     // Handle one tool's stream at a time...
+/// }
+/// # }
+/// ```
+// IDs contain interior-mutable platform handles, but their hash and equality never change.
+#[allow(clippy::mutable_key_type)]
+pub fn by_tool<'a>(
+    events: impl IntoIterator<Item = Event<'a>>,
+) -> std::collections::HashMap<crate::tool::ID, Vec<Event<'a>>> {
+    let mut grouped = std::collections::HashMap::<_, Vec<_>>::new();
+    for event in events {
+        if let Event::Tool { tool, .. } = event {
+            grouped.entry(tool.id()).or_default().push(event);
+        }
+    }
+    grouped
+}
@@ -16,6 +16,7 @@ pub enum ToolEvent<Id> {
     // This variant is many times the size of all the others resulting in huge inefficiency.
     // If memory usage/throughput becomes appreciably bad, this is a good place to start.
     Pose(super::Pose),
+    PredictedPose(super::Pose),
     Frame(Option<super::FrameTimestamp>),
     Up,
     Out,
@@ -32,6 +33,7 @@ impl<Id> ToolEvent<Id> {
             Self::Down => ToolEvent::Down,
             Self::Button { button_id, pressed } => ToolEvent::Button { button_id, pressed },
             Self::Pose(v) => ToolEvent::Pose(v),
+            Self::PredictedPose(v) => ToolEvent::PredictedPose(v),
             Self::Frame(v) => ToolEvent::Frame(v),
             Self::Up => ToolEvent::Up,
             Self::Out => ToolEvent::Out,
@@ -80,6 +82,7 @@ impl<Id> PadEvent<Id> {
 pub enum PadGroupEvent<Id> {
     Ring {
         ring: Id,
+        mode: Option<u32>,
         event: super::TouchStripEvent,
     },
     Strip {
@@ -92,8 +95,9 @@ impl<Id> PadGroupEvent<Id> {
     // Can't impl `From`, due to conflict with `From<T> for T` :(
     pub fn id_into<Into: From<Id>>(self) -> PadGroupEvent<Into> {
         match self {
-            Self::Ring { ring, event } => PadGroupEvent::Ring {
+            Self::Ring { ring, mode, event } => PadGroupEvent::Ring {
                 ring: Into::from(ring),
+                mode,
                 event,
             },
             Self::Strip { strip, event } => PadGroupEvent::Strip {
@@ -43,6 +43,8 @@ use platform::{InternalID, PlatformImpl};
 
 pub mod axis;
 pub mod builder;
+#[cfg(feature = "egui")]
+pub mod egui;
 pub mod events;
 pub mod pad;
 pub mod tablet;
@@ -91,10 +93,45 @@ pub enum PumpError {
     WaylandDispatch(#[from] wayland_client::DispatchError),
 }
 
+/// A snapshot of the health of a [`Manager`], for troubleshooting. See [`Manager::diagnostics`].
+///
+/// Useful to tell "no hardware found" apart from "hardware found, but no events arriving."
+#[derive(Clone, Copy, Debug)]
+pub struct Diagnostics {
+    /// The API in use, see [`Manager::backed`].
+    pub backend: Backend,
+    /// Number of tablets currently reported.
+    pub tablets: usize,
+    /// Number of tools currently reported.
+    pub tools: usize,
+    /// Number of pads currently reported.
+    pub pads: usize,
+    /// Total events received from the platform since the manager was created, including device `Added` and `Removed` events.
+    ///
+    /// This is an upper bound on the events yielded by [`Manager::pump`], see [`Manager::event_count`].
+    pub events_received: u64,
+}
+
 /// Maintains a connection to the OS's tablet server. This is the main
 /// entry point for enumerating hardware and listening for events.
+///
+/// # Dropping
+/// Once dropped, no further events are produced, and tools that were `In` or `Down` at that point will never report
+/// `Up` or `Out`. To close in-progress strokes, call [`Manager::shutdown`] before dropping. Neither supported platform
+/// grabs devices, so there is nothing else to release.
 pub struct Manager {
     pub(crate) internal: platform::PlatformManager,
+    /// Running total of events, for [`Diagnostics::events_received`].
+    pub(crate) events_received: u64,
+    /// Ring buffer of the last `history_len` events, see [`Manager::recent_events`].
+    pub(crate) history: std::collections::VecDeque<events::RecordedEvent>,
+    pub(crate) history_len: usize,
+    /// State of tools currently in proximity, see [`Manager::tool_state`].
+    pub(crate) tool_states: Vec<(InternalID, tool::ToolState)>,
+    /// Timestamp of the most recent tool frame, see [`Manager::last_event_time`].
+    pub(crate) last_event_time: Option<events::FrameTimestamp>,
+    /// `Some` once [`Manager::shutdown`], holding the events it synthesized. These replace the platform's events.
+    pub(crate) closing_events: Option<Vec<events::raw::Event<InternalID>>>,
     // `_backing` MUST BE LAST IN DECLARATION ORDER!
     // the other fields may rely on the lifetime guarantees granted by the contents
     // of this `Backing`, and it's guaranteed that drop order == declaration order.
@@ -107,9 +144,150 @@ impl Manager {
     /// This will not wait for new events, and will return immediately with empty events if there is nothing to do.
     #[allow(clippy::missing_errors_doc)]
     pub fn pump(&mut self) -> Result<Events<'_>, PumpError> {
+        if let Some(closing) = &mut self.closing_events {
+            // Shut down, nothing more to report.
+            closing.clear();
+            return Ok(Events { manager: &*self });
+        }
         self.internal.pump()?;
+        self.record_events();
         Ok(Events { manager: &*self })
     }
+    /// End all interactions, returning [`Up`](events::ToolEvent::Up) and [`Out`](events::ToolEvent::Out) events
+    /// for every tool still [`Down`](events::ToolEvent::Down) or [`In`](events::ToolEvent::In), so that consumers
+    /// holding stroke state can close it cleanly.
+    ///
+    /// Afterwards, no further events are produced - [`Manager::pump`] returns no events - though hardware is still
+    /// listed. Call this right before dropping the `Manager`. Calling it again returns no events.
+    pub fn shutdown(&mut self) -> Events<'_> {
+        use events::raw::{Event as RawEvent, ToolEvent as RawTool};
+        let mut closing = self.closing_events.take().unwrap_or_default();
+        closing.clear();
+        for (tool, state) in &self.tool_states {
+            let mut push = |event| {
+                closing.push(RawEvent::Tool {
+                    tool: tool.clone(),
+                    event,
+                });
+            };
+            if state.down {
+                push(RawTool::Up);
+            }
+            push(RawTool::Out);
+            push(RawTool::Frame(None));
+        }
+        self.closing_events = Some(closing);
+        self.record_events();
+        Events { manager: &*self }
+    }
+    /// The events of the last pump, or of [`Manager::shutdown`].
+    pub(crate) fn raw_events(&self) -> platform::RawEventsIter<'_> {
+        Self::raw_events_of(&self.internal, self.closing_events.as_deref())
+    }
+    /// See [`Manager::raw_events`].
+    /// (can't take a self param, for use while other fields are borrowed mutably.)
+    fn raw_events_of<'a>(
+        internal: &'a platform::PlatformManager,
+        closing_events: Option<&'a [events::raw::Event<InternalID>]>,
+    ) -> platform::RawEventsIter<'a> {
+        match closing_events {
+            Some(closing) => platform::RawEventsIter::Synthetic(closing.iter()),
+            None => internal.raw_events(),
+        }
+    }
+    /// Fold freshly received events into the manager's own bookkeeping.
+    fn record_events(&mut self) {
+        self.events_received += self.event_count() as u64;
+        if self.history_len != 0 {
+            self.history.extend(
+                Self::raw_events_of(&self.internal, self.closing_events.as_deref())
+                    .map(events::RecordedEvent),
+            );
+            let excess = self.history.len().saturating_sub(self.history_len);
+            self.history.drain(..excess);
+        }
+        self.update_tool_states();
+        self.last_event_time = self
+            .raw_events()
+            .filter_map(|event| match event {
+                events::raw::Event::Tool {
+                    event: events::raw::ToolEvent::Frame(time),
+                    ..
+                } => time,
+                _ => None,
+            })
+            .last()
+            .or(self.last_event_time);
+    }
+    /// Query whether the last call to [`Manager::pump`] received any events, without iterating them.
+    ///
+    /// Useful for skipping work, such as a redraw, when nothing happened. When `false`, iterating is guaranteed to yield
+    /// nothing. When `true`, see [`Manager::event_count`] - iterating may still yield nothing.
+    #[must_use]
+    pub fn has_events(&self) -> bool {
+        self.event_count() != 0
+    }
+    /// Query how many events the last call to [`Manager::pump`] received, without iterating them.
+    ///
+    /// This is an upper bound rather than an exact count: events referring to hardware that no longer exists are
+    /// skipped during iteration, so iterating may yield fewer.
+    #[must_use]
+    pub fn event_count(&self) -> usize {
+        self.raw_events().len()
+    }
+    /// The timestamp of the most recent tool [`Frame`](events::ToolEvent::Frame) delivered by [`Manager::pump`], on the
+    /// server's clock. Persists across pumps with no timestamped frames.
+    ///
+    /// `None` if no timestamped frame has been seen yet, or if the platform does not report timestamps. Pad ring and
+    /// strip frames are not considered, as their timestamps are not necessarily on the same clock.
+    #[must_use]
+    pub fn last_event_time(&self) -> Option<events::FrameTimestamp> {
+        self.last_event_time
+    }
+    /// Collect a snapshot of device and event counts, for surfacing on a troubleshooting screen.
+    ///
+    /// Neither supported platform exposes input grabs, so there is no grab state to report - if hardware is listed but
+    /// no events arrive, the window is likely not receiving tablet focus.
+    #[must_use]
+    pub fn diagnostics(&self) -> Diagnostics {
+        Diagnostics {
+            backend: self.backed(),
+            tablets: self.tablets().len(),
+            tools: self.tools().len(),
+            pads: self.pads().len(),
+            events_received: self.events_received,
+        }
+    }
+    /// Query the live state of a tool, accumulated from its events. `None` if the tool is not currently
+    /// [in proximity](events::ToolEvent::In).
+    #[must_use]
+    pub fn tool_state(&self, tool: &tool::Tool) -> Option<&tool::ToolState> {
+        self.tool_states
+            .iter()
+            .find(|(id, _)| *id == tool.internal_id)
+            .map(|(_, state)| state)
+    }
+    /// Query whether any tool is currently [in proximity](events::ToolEvent::In) of this window, as of the last
+    /// [`Manager::pump`]. Useful for hiding the mouse cursor while a stylus hovers, for example.
+    #[must_use]
+    pub fn has_tablet_focus(&self) -> bool {
+        !self.tool_states.is_empty()
+    }
+    /// Query the buttons currently held on a tool. Empty if the tool is not currently [in proximity](events::ToolEvent::In),
+    /// as buttons are considered released when the tool leaves.
+    #[must_use]
+    pub fn tool_buttons(&self, tool: &tool::Tool) -> &[tool::ButtonID] {
+        self.tool_state(tool)
+            .map_or(&[], tool::ToolState::pressed_buttons)
+    }
+    /// Iterate over up to the last [`Builder::event_history`] events, oldest first, including those of previous pumps.
+    /// Empty if history is disabled.
+    ///
+    /// Events of hardware that has since been removed are kept, including its `Removed` event, as the moments around
+    /// a hotplug are often the interesting part of a crash report. See [`events::RecordedEvent`].
+    pub fn recent_events(&self) -> impl ExactSizeIterator<Item = &events::RecordedEvent> + '_ {
+        self.history.iter()
+    }
     /// Query the precision of [timestamps](events::FrameTimestamp) provided along with axis events, if any.
     /// This does *not* represent the polling rate. `None` if timestamps are not collected.
     ///
@@ -132,7 +310,8 @@ impl Manager {
     /// and may have touch support, an inbuilt display, lights, buttons, rings, and/or sliders.
     /// Hardware reports are updated on each call to [`Manager::pump`].
     ///
-    /// Pads are ordered arbitrarily.
+    /// Pads are ordered by when they were added - a newly added pad is always last, and removals don't reorder the rest.
+    /// Positions still shift when an earlier pad is removed, so prefer [`pad::Pad::id`] for tracking a specific pad.
     ///
     /// # Platform support
     /// * Wayland only.
@@ -144,17 +323,68 @@ impl Manager {
     /// communicate with one or more pads, and are responsible for reporting movements, pressure, etc.,
     /// and may have multiple buttons. Hardware reports are updated on each call to [`Manager::pump`].
     ///
-    /// Tools are ordered arbitrarily.
+    /// Tools are ordered by when they were added - a newly added tool is always last, and removals don't reorder the rest.
+    /// Positions still shift when an earlier tool is removed, so prefer [`tool::Tool::id`] for tracking a specific tool.
     #[must_use]
     pub fn tools(&self) -> &[tool::Tool] {
         self.internal.tools()
     }
+    /// Find a tool by its [`HardwareID`](tool::HardwareID), for example to restore per-pen settings after a replug.
+    ///
+    /// Related tools share a hardware ID, such as a pen and its eraser. If several tools match, the first added is
+    /// returned - filter [`Manager::tools`] to find them all.
+    #[must_use]
+    pub fn tool_by_hardware_id(&self, hardware_id: tool::HardwareID) -> Option<&tool::Tool> {
+        self.tools()
+            .iter()
+            .find(|tool| tool.hardware_id == Some(hardware_id))
+    }
+    /// Find a tool by its [`wacom_id`](tool::Tool::wacom_id).
+    ///
+    /// This identifies the model of tool rather than the individual hardware, so it is shared by tools of the same
+    /// model. If several tools match, the first added is returned - filter [`Manager::tools`] to find them all.
+    #[must_use]
+    pub fn tool_by_wacom_id(&self, wacom_id: u64) -> Option<&tool::Tool> {
+        self.tools()
+            .iter()
+            .find(|tool| tool.wacom_id == Some(wacom_id))
+    }
     /// A tablet is the entry point for interactive devices, and the top level of the hierarchy
     /// which may expose several pads or tools. Hardware reports are updated on each call to [`Manager::pump`].
     ///
-    /// Tablets are ordered arbitrarily.
+    /// Tablets are ordered by when they were added - a newly added tablet is always last, and removals don't reorder the rest.
+    /// Positions still shift when an earlier tablet is removed, so prefer [`tablet::Tablet::id`] for tracking a specific tablet.
     #[must_use]
     pub fn tablets(&self) -> &[tablet::Tablet] {
         self.internal.tablets()
     }
 }
+impl Manager {
+    /// Fold this pump's events into the [`tool::ToolState`]s.
+    fn update_tool_states(&mut self) {
+        use events::raw::{Event as RawEvent, ToolEvent as RawTool};
+        for event in Self::raw_events_of(&self.internal, self.closing_events.as_deref()) {
+            let RawEvent::Tool { tool, event } = event else {
+                continue;
+            };
+            let pos = self.tool_states.iter().position(|(id, _)| *id == tool);
+            match (event, pos) {
+                // Leaving, forget everything. (Frames may still follow the Out, these are ignored below)
+                (RawTool::Out | RawTool::Removed, Some(pos)) => {
+                    self.tool_states.swap_remove(pos);
+                }
+                (event, Some(pos)) => self.tool_states[pos].1.update(&event),
+                // Only start tracking on In, so stray events after Out don't resurrect the state.
+                (event @ RawTool::In { .. }, None) => {
+                    let mut state = tool::ToolState::default();
+                    state.update(&event);
+                    self.tool_states.push((tool, state));
+                }
+                (_, None) => (),
+            }
+        }
+        // Tools may disappear without a `Removed` on some platforms.
+        self.tool_states
+            .retain(|(id, _)| self.internal.tools().iter().any(|t| t.internal_id == *id));
+    }
+}
@@ -18,7 +18,6 @@ pub use group::Group;
 pub use ring::Ring;
 pub use strip::Strip;
 
-#[derive(Debug)]
 pub struct Pad {
     pub(crate) internal_id: crate::InternalID,
     /// How many buttons total are on this pad? Buttons may be further reserved by groups, see [`Group::buttons`] for associating
@@ -27,8 +26,20 @@ pub struct Pad {
     /// Groups within this pad. Always at least one.
     // (todo: make that a type-level guarantee)
     pub groups: Vec<Group>,
+    /// The tablet this pad is currently associated with, see [`PadEvent::Enter`](crate::events::PadEvent::Enter).
+    pub(crate) tablet: Option<crate::InternalID>,
 }
 crate::util::macro_bits::impl_get_id!(ID for Pad);
+// Manual impl to keep platform internals out of logs.
+impl std::fmt::Debug for Pad {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pad")
+            .field("id", &self.id())
+            .field("total_buttons", &self.total_buttons)
+            .field("groups", &self.groups)
+            .finish_non_exhaustive()
+    }
+}
 // Submodules for nicer ID names.
 pub mod group {
     /// The type of interactable being queried in a [`FeedbackFn`]
@@ -51,6 +62,11 @@ pub mod group {
         /// How many mode layers does this group cycle through?
         /// If None, the group does not expose the ability to shift through modes.
         pub mode_count: Option<std::num::NonZeroU32>,
+        /// The zero-indexed mode layer that is currently active, as of the last [`pump`](crate::Manager::pump).
+        /// `None` if the server has not yet reported it. Groups without modes always report the single mode `Some(0)`.
+        ///
+        /// See [`PadGroupEvent::Mode`](crate::events::PadGroupEvent::Mode) for change notifications.
+        pub current_mode: Option<u32>,
         /// Sorted list of the pad button indices that are owned by this group.
         /// This is some subset of the [buttons reported by the Pad](super::Pad::total_buttons).
         pub buttons: Vec<u32>,
@@ -68,14 +84,15 @@ pub mod group {
     impl std::fmt::Debug for Group {
         fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             let mut this = fmt.debug_struct("PadGroup");
-            this.field("internal_id", &self.internal_id);
+            this.field("id", &self.id());
             this.field("mode_count", &self.mode_count);
+            this.field("current_mode", &self.current_mode);
             this.field("buttons", &self.buttons);
             this.field("rings", &self.rings);
             this.field("strips", &self.strips);
             // !Debug, so just opaquely show whether it's some or None
             this.field("feeback", &self.feedback.as_ref().map(|_| "..."));
-            this.finish()
+            this.finish_non_exhaustive()
         }
     }
     crate::util::macro_bits::impl_get_id!(ID for Group);
@@ -90,13 +107,95 @@ pub enum TouchSource {
 }
 pub mod ring {
     /// A continuous circular touch-sensitive area or scrollwheel, reporting absolute position in radians clockwise from "logical north."
+    ///
+    /// # Angular convention
+    /// The convention is fixed on every platform, so there is nothing to query at runtime:
+    /// * Angles are in `[0, TAU)`.
+    /// * Zero is logical north, the top of the ring in the pad's current rotation. Pads configured for left-handed use
+    ///   are rotated by the platform before reporting, so zero is always "up" from the user's point of view.
+    /// * Angles increase clockwise, as seen when looking down at the pad.
+    ///
+    /// Thus a knob UI drawn with zero at the top and increasing clockwise matches the hardware directly.
     #[derive(Debug)]
     pub struct Ring {
         pub(crate) internal_id: crate::InternalID,
         /// Granularity of the reported angle, if known.
         pub granularity: Option<crate::axis::Granularity>,
+        pub(crate) current_angle: Option<f32>,
     }
     crate::util::macro_bits::impl_get_id!(ID for Ring);
+    impl Ring {
+        /// The most recently reported angle, as of the last [`pump`](crate::Manager::pump), in the same units as
+        /// [`TouchStripEvent::Pose`](crate::events::TouchStripEvent::Pose). `None` if the ring has never been touched.
+        ///
+        /// This is retained after the interaction ends, for displaying the resting position of the ring.
+        #[must_use]
+        pub fn current_angle(&self) -> Option<f32> {
+            self.current_angle
+        }
+    }
+
+    /// Converts the stream of absolute angles reported by a single ring into signed deltas, for
+    /// treating a ring like a relative scroll wheel.
+    ///
+    /// Keep one of these per [`ID`], and feed it every [`TouchStripEvent`](crate::events::TouchStripEvent) of that ring.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct DeltaTracker {
+        /// The last angle seen during the current interaction, `None` between interactions.
+        last: Option<f32>,
+    }
+    impl DeltaTracker {
+        #[must_use]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        /// Update with the next event from the ring, returning the change in angle in radians since the last pose,
+        /// positive being clockwise.
+        ///
+        /// Crossing the `0`/`TAU` boundary is handled by always taking the shortest arc between two angles.
+        /// The first pose of an interaction (after creation or an [`Up`](crate::events::TouchStripEvent::Up)) has nothing to compare
+        /// against and reports `None`, so lifting and touching down elsewhere on the ring never produces a jump.
+        pub fn update(&mut self, event: crate::events::TouchStripEvent) -> Option<f32> {
+            use crate::events::TouchStripEvent;
+            match event {
+                TouchStripEvent::Pose(angle) => {
+                    let delta = self.last.map(|last| radial_delta(last, angle));
+                    self.last = Some(angle);
+                    delta
+                }
+                TouchStripEvent::Up => {
+                    self.last = None;
+                    None
+                }
+                TouchStripEvent::Frame(_) | TouchStripEvent::Source(_) => None,
+            }
+        }
+    }
+    /// Shortest signed arc from `from` to `to` in radians, positive being clockwise. Both angles are expected in `[0, TAU)`,
+    /// as reported by rings, and the result is in `[-PI, PI]`.
+    ///
+    /// Naively subtracting two angles jumps by almost a full turn when crossing the `0`/`TAU` boundary, this
+    /// instead takes the shortest way around:
+    /// ```
+    /// # use octotablet::pad::ring::radial_delta;
+    /// use std::f32::consts::TAU;
+    /// // Clockwise across the boundary...
+    /// assert!((radial_delta(TAU - 0.1, 0.1) - 0.2).abs() < 1e-5);
+    /// // ...and back again.
+    /// assert!((radial_delta(0.1, TAU - 0.1) + 0.2).abs() < 1e-5);
+    /// ```
+    ///
+    /// [`DeltaTracker`] applies this to a stream of ring events.
+    #[must_use]
+    pub fn radial_delta(from: f32, to: f32) -> f32 {
+        use std::f32::consts::{PI, TAU};
+        let delta = (to - from).rem_euclid(TAU);
+        if delta > PI {
+            delta - TAU
+        } else {
+            delta
+        }
+    }
 }
 pub mod strip {
     /// A touch-sensitive strip or slider, reporting absolute position in `0..=1` where 0 is "logical top/left."
@@ -87,6 +87,8 @@ impl tablet_pc::IStylusPlugin_Impl for Plugin {
 
             // Remove it from the map - missing from map represents the stylus is Out.
             let old_phase = lock.stylus_states.remove(&id);
+            lock.predictors.remove(&id);
+            lock.last_poses.remove(&id);
 
             // The stylus was busy. Emit appropriate events to yank it away
             if let Some(old_phase) = old_phase {
@@ -285,13 +287,13 @@ impl tablet_pc::IStylusPlugin_Impl for Plugin {
             };
 
             let tool = *tool.internal_id.unwrap_ink();
-            lock.events.push(crate::events::raw::Event::Tool {
+            lock.push_button(
                 tool,
-                event: crate::events::raw::ToolEvent::Button {
+                crate::events::raw::ToolEvent::Button {
                     button_id: ButtonID(button_guid).into(),
                     pressed: true,
                 },
-            });
+            );
 
             Ok(())
         }))
@@ -323,13 +325,13 @@ impl tablet_pc::IStylusPlugin_Impl for Plugin {
             };
 
             let tool = *tool.internal_id.unwrap_ink();
-            lock.events.push(crate::events::raw::Event::Tool {
+            lock.push_button(
                 tool,
-                event: crate::events::raw::ToolEvent::Button {
+                crate::events::raw::ToolEvent::Button {
                     button_id: ButtonID(button_guid).into(),
-                    pressed: true,
+                    pressed: false,
                 },
-            });
+            );
 
             Ok(())
         }))
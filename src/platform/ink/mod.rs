@@ -107,6 +107,18 @@ struct DataFrame {
     himetric_to_logical_pixel: f32,
     /// Keep track of the current state of styluses. Whether included at all indicates In/Out state.
     stylus_states: std::collections::BTreeMap<ID, StylusPhase>,
+    /// From the builder, see `Builder::predict_motion`.
+    predict_motion: Option<std::time::Duration>,
+    /// From the builder, see `Builder::synthesize_binary_pressure`.
+    synthesize_binary_pressure: bool,
+    /// From the builder, see `Builder::tilt_unit`.
+    tilt_unit: crate::axis::AngleUnit,
+    /// Motion prediction state of In styluses, when enabled. Removed on Out.
+    predictors: std::collections::BTreeMap<ID, crate::platform::predict::Predictor>,
+    /// From the builder, see `Builder::pose_on_button`.
+    pose_on_button: bool,
+    /// Last pose of In styluses, when `pose_on_button` is enabled. Removed on Out.
+    last_poses: std::collections::BTreeMap<ID, crate::axis::Pose>,
     tools: Vec<crate::tool::Tool>,
     /// Indicies of `raw_tablets` which have been queued for destruction after events are consumed.
     /// This is the *actual* subscript into the list, *not* apparent index (index where deleted ones aren't counted)
@@ -130,6 +142,12 @@ impl Clone for DataFrame {
             raw_tablet_deletions: vec![],
             raw_tablets: vec![],
             stylus_states: std::collections::BTreeMap::new(),
+            predict_motion: None,
+            synthesize_binary_pressure: false,
+            tilt_unit: crate::axis::AngleUnit::Radians,
+            predictors: std::collections::BTreeMap::new(),
+            pose_on_button: false,
+            last_poses: std::collections::BTreeMap::new(),
             tools: vec![],
             tablets: vec![],
             events: vec![],
@@ -144,6 +162,12 @@ impl Clone for DataFrame {
             hwnd,
             himetric_to_logical_pixel,
             stylus_states,
+            predict_motion,
+            synthesize_binary_pressure,
+            tilt_unit,
+            predictors,
+            pose_on_button,
+            last_poses,
             tools,
             raw_tablet_deletions,
             raw_tablets,
@@ -153,6 +177,12 @@ impl Clone for DataFrame {
         *himetric_to_logical_pixel = source.himetric_to_logical_pixel;
         *hwnd = source.hwnd;
         stylus_states.clone_from(&source.stylus_states);
+        *predict_motion = source.predict_motion;
+        *synthesize_binary_pressure = source.synthesize_binary_pressure;
+        *tilt_unit = source.tilt_unit;
+        predictors.clone_from(&source.predictors);
+        *pose_on_button = source.pose_on_button;
+        last_poses.clone_from(&source.last_poses);
 
         tools.clear();
         tools.extend(
@@ -208,6 +238,12 @@ impl DataFrame {
             himetric_to_logical_pixel: _,
             hwnd: _,
             stylus_states,
+            predict_motion: _,
+            synthesize_binary_pressure: _,
+            tilt_unit: _,
+            predictors,
+            pose_on_button: _,
+            last_poses,
             tools,
             raw_tablet_deletions,
             raw_tablets,
@@ -216,6 +252,8 @@ impl DataFrame {
         } = self;
 
         stylus_states.clear();
+        predictors.clear();
+        last_poses.clear();
         tools.clear();
         raw_tablet_deletions.clear();
         raw_tablets.clear();
@@ -223,6 +261,26 @@ impl DataFrame {
         events.clear();
         self
     }
+    /// Push a tool button event. If enabled, the last pose of the tool is repeated alongside it in a frame of its own,
+    /// see `Builder::pose_on_button`.
+    fn push_button(&mut self, tool: ID, event: crate::events::raw::ToolEvent<ID>) {
+        let pose = self.last_poses.get(&tool).copied();
+        if let Some(pose) = pose {
+            self.events.push(crate::events::raw::Event::Tool {
+                tool,
+                event: crate::events::raw::ToolEvent::Pose(pose),
+            });
+        }
+        self.events
+            .push(crate::events::raw::Event::Tool { tool, event });
+        if pose.is_some() {
+            // Otherwise the pose would join the next packet's frame, which has a pose of its own.
+            self.events.push(crate::events::raw::Event::Tool {
+                tool,
+                event: crate::events::raw::ToolEvent::Frame(None),
+            });
+        }
+    }
     /// Called at the end of each pump to maintain bookkeeping, *after* the clone has occured
     fn frame_end_cleanup(&mut self) {
         self.events.clear();
@@ -303,7 +361,12 @@ impl DataFrame {
                 (
                     RawTabletSlot::Concrete(RawTablet {
                         interpreter,
-                        axes: info,
+                        axes: crate::axis::FullInfo {
+                            tilt: info
+                                .tilt
+                                .map(|tilt| self.tilt_unit.convert_radians_info(tilt)),
+                            ..info
+                        },
                         tcid,
                     }),
                     Some(crate::tablet::Tablet {
@@ -313,6 +376,14 @@ impl DataFrame {
                             .as_ref()
                             .map(ToString::to_string),
                         usb_id: None,
+                        // Mouse emulation shows up as a tablet of the mouse kind.
+                        emulated: {
+                            use core::Interface;
+                            tablet
+                                .cast::<tablet_pc::IInkTablet2>()
+                                .and_then(|tablet| unsafe { tablet.DeviceKind() })
+                                .is_ok_and(|kind| kind == tablet_pc::TDK_Mouse)
+                        },
                     }),
                 )
             } else {
@@ -425,7 +496,6 @@ impl DataFrame {
             use crate::axis::Union;
             tool.axes = tool.axes.union(&tablet.axes);
         }
-
         // Emit events.
         let stylus_id = *(tool.internal_id.unwrap_ink());
         let mut needs_frame = false;
@@ -494,10 +564,39 @@ impl DataFrame {
                 // we have.
                 // packet.status;
 
+                let mut pose = packet.pose;
+                pose.tilt = pose
+                    .tilt
+                    .map(|tilt| tilt.map(|angle| self.tilt_unit.convert_radians(angle)));
+                // Decided per packet, as `tool.axes` is not yet populated on a tool's first packets.
+                if self.synthesize_binary_pressure && pose.pressure.get().is_none() {
+                    pose.pressure = match phase {
+                        StylusPhase::Touched => crate::util::NicheF32::ONE,
+                        StylusPhase::InAir => crate::util::NicheF32::ZERO,
+                    };
+                }
+
                 self.events.push(crate::events::raw::Event::Tool {
                     tool: stylus_id,
-                    event: crate::events::raw::ToolEvent::Pose(packet.pose),
+                    event: crate::events::raw::ToolEvent::Pose(pose),
                 });
+                if self.pose_on_button {
+                    self.last_poses.insert(stylus_id, pose);
+                }
+                // Needs timestamps to know velocity.
+                if let Some((ahead, timestamp)) = self.predict_motion.zip(packet.timestamp) {
+                    if let Some(predicted) = self
+                        .predictors
+                        .entry(stylus_id)
+                        .or_default()
+                        .update(pose, timestamp, ahead)
+                    {
+                        self.events.push(crate::events::raw::Event::Tool {
+                            tool: stylus_id,
+                            event: crate::events::raw::ToolEvent::PredictedPose(predicted),
+                        });
+                    }
+                }
 
                 self.events.push(crate::events::raw::Event::Tool {
                     tool: stylus_id,
@@ -636,6 +735,12 @@ impl Manager {
                 tablets: vec![],
                 tools: vec![],
                 stylus_states: std::collections::BTreeMap::new(),
+                predict_motion: opts.predict_motion,
+                synthesize_binary_pressure: opts.synthesize_binary_pressure,
+                tilt_unit: opts.tilt_unit,
+                predictors: std::collections::BTreeMap::new(),
+                pose_on_button: opts.pose_on_button,
+                last_poses: std::collections::BTreeMap::new(),
                 events: vec![],
                 hwnd,
                 himetric_to_logical_pixel: fetch_himetric_to_logical_pixel(hwnd),
@@ -692,6 +797,13 @@ impl Manager {
             {
                 let crate::builder::Builder {
                     emulate_tool_from_mouse,
+                    // Handled by the `DataFrame`
+                    predict_motion: _,
+                    synthesize_binary_pressure: _,
+                    tilt_unit: _,
+                    pose_on_button: _,
+                    // Handled by the `crate::Manager`
+                    event_history: _,
                 } = opts;
 
                 rts.SetAllTabletsMode(emulate_tool_from_mouse)?;
@@ -404,12 +404,20 @@ fn linear_or_normalize(
                 scaler,
                 axis::LengthInfo::Normalized(axis::NormalizedInfo {
                     granularity: info.granularity,
+                    native_levels: native_levels(info.granularity),
                 }),
             )
         }),
     }
 }
 
+/// The granularity of a normalized raw range is the count of integer values it spans. When that's a power of two,
+/// it's almost certainly the hardware's native level count rather than an arbitrary driver range.
+fn native_levels(granularity: Option<axis::Granularity>) -> Option<u32> {
+    granularity
+        .map(|granularity| granularity.0.get())
+        .filter(|levels| levels.is_power_of_two())
+}
 /// Normalize a raw range into an angular unit. If unrecognized unit, fallback on a normalized range.
 fn half_angle_or_normalize(metrics: tablet_pc::PROPERTY_METRICS) -> Tristate<(Scaler, axis::Info)> {
     match angular_scale_factor(metrics.Units) {
@@ -596,6 +604,7 @@ pub unsafe fn make_interpreter(
                 interpreter.normal_pressure = norm.map_ok(|(a, _)| a);
                 info.pressure = norm.ok().map(|(_, b)| axis::NormalizedInfo {
                     granularity: b.granularity,
+                    native_levels: native_levels(b.granularity),
                 });
             }
             tablet_pc::GUID_PACKETPROPERTY_GUID_BUTTON_PRESSURE => {
@@ -604,6 +613,7 @@ pub unsafe fn make_interpreter(
                 interpreter.button_pressure = norm.map_ok(|(a, _)| a);
                 info.button_pressure = norm.ok().map(|(_, b)| axis::NormalizedInfo {
                     granularity: b.granularity,
+                    native_levels: native_levels(b.granularity),
                 });
             }
 
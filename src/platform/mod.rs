@@ -3,6 +3,9 @@
 pub(crate) mod ink;
 #[cfg(wl_tablet)]
 pub(crate) mod wl;
+// Shared helpers
+#[cfg(any(wl_tablet, ink_rts))]
+pub(crate) mod predict;
 
 /// Holds any one of the internal platform IDs.
 /// Since these are always sealed away as an implementation detail, we can always
@@ -158,6 +161,8 @@ pub(crate) enum RawEventsIter<'a> {
     Wayland(std::slice::Iter<'a, crate::events::raw::Event<wl::ID>>),
     #[cfg(ink_rts)]
     Ink(std::slice::Iter<'a, crate::events::raw::Event<ink::ID>>),
+    /// Events made up by the `Manager` itself, see `Manager::shutdown`.
+    Synthetic(std::slice::Iter<'a, crate::events::raw::Event<InternalID>>),
 }
 impl Iterator for RawEventsIter<'_> {
     type Item = crate::events::raw::Event<InternalID>;
@@ -168,9 +173,21 @@ impl Iterator for RawEventsIter<'_> {
             Self::Wayland(wl) => wl.next().cloned().map(crate::events::raw::Event::id_into),
             #[cfg(ink_rts)]
             Self::Ink(ink) => ink.next().cloned().map(crate::events::raw::Event::id_into),
+            Self::Synthetic(synthetic) => synthetic.next().cloned(),
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            #[cfg(wl_tablet)]
+            Self::Wayland(wl) => wl.size_hint(),
+            #[cfg(ink_rts)]
+            Self::Ink(ink) => ink.size_hint(),
+            Self::Synthetic(synthetic) => synthetic.size_hint(),
         }
     }
 }
+// Each variant is a slice iter, which is exact.
+impl ExactSizeIterator for RawEventsIter<'_> {}
 
 /// Trait that all platforms implement, giving the main `Manager` higher-level access to the black box.
 #[enum_dispatch::enum_dispatch]
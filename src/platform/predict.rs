@@ -0,0 +1,46 @@
+//! Backend-agnostic motion prediction, see [`crate::Builder::predict_motion`].
+
+use crate::{axis::Pose, events::FrameTimestamp, util::NicheF32};
+
+/// Linearly extrapolates poses of a single tool from the last two measured samples.
+///
+/// Reset (or drop) when the tool goes Out, so that a new interaction doesn't extrapolate
+/// from wherever the last one ended.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Predictor {
+    last: Option<(Pose, FrameTimestamp)>,
+}
+impl Predictor {
+    /// Feed a measured pose, returning a pose predicted `ahead` into the future, or `None` if
+    /// there isn't enough history yet.
+    ///
+    /// Only position and pressure are extrapolated, other axes are carried over from `pose` as-is.
+    pub fn update(
+        &mut self,
+        pose: Pose,
+        time: FrameTimestamp,
+        ahead: std::time::Duration,
+    ) -> Option<Pose> {
+        let (last_pose, last_time) = self.last.replace((pose, time))?;
+        let elapsed = time.duration_since(last_time).as_secs_f32();
+        // Duplicate timestamps give no notion of velocity.
+        if elapsed <= 0.0 {
+            return None;
+        }
+        // How many "last steps" to extrapolate by.
+        let steps = ahead.as_secs_f32() / elapsed;
+        let extrapolate = |from: f32, to: f32| to + (to - from) * steps;
+
+        let mut predicted = pose;
+        predicted.position = [
+            extrapolate(last_pose.position[0], pose.position[0]),
+            extrapolate(last_pose.position[1], pose.position[1]),
+        ];
+        if let (Some(from), Some(to)) = (last_pose.pressure.get(), pose.pressure.get()) {
+            // Stay within the normalized range - no negative force when lifting off, nor overshooting full force.
+            predicted.pressure =
+                NicheF32::new_some(extrapolate(from, to).clamp(0.0, 1.0)).unwrap_or(NicheF32::NONE);
+        }
+        Some(predicted)
+    }
+}
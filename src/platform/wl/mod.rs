@@ -40,7 +40,16 @@ impl Manager {
     /// # Safety
     /// The given display pointer must be valid as long as the returned `Manager` is alive.
     pub(crate) unsafe fn build_wayland_display(
-        _: crate::builder::Builder,
+        crate::builder::Builder {
+            predict_motion,
+            synthesize_binary_pressure,
+            tilt_unit,
+            // Only supported on Ink.
+            emulate_tool_from_mouse: _,
+            pose_on_button: _,
+            // Handled by the `crate::Manager`
+            event_history: _,
+        }: crate::builder::Builder,
         wl_display: *mut (),
     ) -> Manager {
         // Safety - deferred to this fn's contract
@@ -57,7 +66,12 @@ impl Manager {
             _conn: conn,
             queue,
             _qh: qh,
-            state: TabletState::default(),
+            state: TabletState {
+                predict_motion,
+                synthesize_binary_pressure,
+                tilt_unit,
+                ..Default::default()
+            },
         }
     }
 }
@@ -125,6 +139,7 @@ impl HasWlId for Tablet {
             internal_id: id.into(),
             name: None,
             usb_id: None,
+            emulated: false,
         }
     }
     fn id(&self) -> &ID {
@@ -149,6 +164,7 @@ impl HasWlId for Pad {
             // the constructor for this value is never sent.
             total_buttons: 0,
             groups: Vec::new(),
+            tablet: None,
         }
     }
     fn id(&self) -> &ID {
@@ -159,7 +175,11 @@ impl HasWlId for Pad {
 }
 impl HasWlId for Group {
     type DoneError = std::convert::Infallible;
-    fn done(self) -> Result<Self, Self::DoneError> {
+    fn done(mut self) -> Result<Self, Self::DoneError> {
+        // No modes advertised - the group is forever in its one and only mode.
+        if self.mode_count.is_none() {
+            self.current_mode = Some(0);
+        }
         Ok(self)
     }
     fn new_default(id: ID) -> Self {
@@ -170,6 +190,7 @@ impl HasWlId for Group {
             strips: Vec::new(),
             feedback: None,
             mode_count: None,
+            current_mode: None,
         }
     }
     fn id(&self) -> &ID {
@@ -187,6 +208,7 @@ impl HasWlId for Ring {
         Ring {
             internal_id: id.into(),
             granularity: None,
+            current_angle: None,
         }
     }
     fn id(&self) -> &ID {
@@ -264,6 +286,8 @@ enum FrameState {
 struct FrameInProgress {
     tool: ID,
     state_transition: Option<FrameState>,
+    // Whether the tool is Down, as of the end of this frame.
+    down: bool,
     position: Option<[f32; 2]>,
     distance: Option<f32>,
     pressure: Option<f32>,
@@ -274,6 +298,8 @@ struct FrameInProgress {
     // Stream of button events that happened during this frame.
     // By the nature of frames, these are considered to have happened at the same time, but order is still preserved.
     buttons: smallvec::SmallVec<[(u32, bool); 1]>,
+    // Lives as long as the frame does, thus is reset on Out.
+    predictor: crate::platform::predict::Predictor,
 }
 
 enum ConstructID {
@@ -309,6 +335,12 @@ struct TabletState {
     // Partial and complete event tracking.
     frames_in_progress: Vec<FrameInProgress>,
     events: Vec<crate::events::raw::Event<ID>>,
+    // From the builder, see `Builder::predict_motion`.
+    predict_motion: Option<std::time::Duration>,
+    // From the builder, see `Builder::synthesize_binary_pressure`.
+    synthesize_binary_pressure: bool,
+    // From the builder, see `Builder::tilt_unit`.
+    tilt_unit: crate::axis::AngleUnit,
 }
 impl TabletState {
     fn destroy_tool(&mut self, tool: ID) {
@@ -354,6 +386,7 @@ impl TabletState {
             self.frames_in_progress.push(FrameInProgress {
                 tool,
                 state_transition: None,
+                down: false,
                 position: None,
                 distance: None,
                 pressure: None,
@@ -362,11 +395,24 @@ impl TabletState {
                 wheel: None,
                 slider: None,
                 buttons: smallvec::SmallVec::new(),
+                predictor: crate::platform::predict::Predictor::default(),
             });
             self.frames_in_progress.last_mut().unwrap()
         }
     }
+    /// If enabled and the tool has no pressure axis, the binary pressure to report instead, see
+    /// `Builder::synthesize_binary_pressure`.
+    /// (can't take a self param, as it's called while a frame in progress is borrowed.)
+    fn synth_pressure(enabled: bool, tools: &[Tool], tool: &ID, down: bool) -> Option<NicheF32> {
+        let synthesize = enabled
+            && tools
+                .iter()
+                .find(|t| HasWlId::id(*t) == tool)
+                .is_some_and(|t| t.axes.pressure.is_none());
+        synthesize.then_some(if down { NicheF32::ONE } else { NicheF32::ZERO })
+    }
     fn frame(&mut self, tool: &ID, millis: u32) {
+        let time = FrameTimestamp(std::time::Duration::from_millis(u64::from(millis)));
         // Emit the frame. Notably, we leave the frame intact - only changed values are reported by the server,
         // so this allows previous values to be inherited.
         let clear = if let Some(frame) = self
@@ -391,6 +437,11 @@ impl TabletState {
                 }),
                 _ => (),
             }
+            match frame.state_transition {
+                Some(FrameState::Down) => frame.down = true,
+                Some(FrameState::Up | FrameState::Out) => frame.down = false,
+                _ => (),
+            }
             // Emit pose...
             // Position is the only required axis.
             // We explicity do *not* check that the reported axes line up with the capabilities of the tool.
@@ -399,11 +450,18 @@ impl TabletState {
             if let Some(position) = frame.position.filter(|[x, y]| !x.is_nan() && !y.is_nan()) {
                 // Filter to prevent NaN's. This is not currently an invariant we guarantee since I can't figure out how
                 // to ergonomically express it at the type level, but the legwork is already done:
+                let pressure = Self::synth_pressure(
+                    self.synthesize_binary_pressure,
+                    &self.tools,
+                    tool,
+                    frame.down,
+                )
+                .unwrap_or_else(|| frame.pressure.try_into().unwrap_or(NicheF32::NONE));
                 let pose = Pose {
                     position,
                     // Try to make the Option into Niche'd option. If NaN, fail back to None.
                     distance: frame.distance.try_into().unwrap_or(NicheF32::NONE),
-                    pressure: frame.pressure.try_into().unwrap_or(NicheF32::NONE),
+                    pressure,
                     roll: frame.roll.try_into().unwrap_or(NicheF32::NONE),
                     slider: frame.slider.try_into().unwrap_or(NicheF32::NONE),
                     tilt: frame.tilt.filter(|[x, y]| !x.is_nan() && !y.is_nan()),
@@ -416,6 +474,15 @@ impl TabletState {
                     tool: tool.clone(),
                     event: raw_events::ToolEvent::Pose(pose),
                 });
+                if let Some(predicted) = self
+                    .predict_motion
+                    .and_then(|ahead| frame.predictor.update(pose, time, ahead))
+                {
+                    self.events.push(raw_events::Event::Tool {
+                        tool: tool.clone(),
+                        event: raw_events::ToolEvent::PredictedPose(predicted),
+                    });
+                }
             }
             // Emit buttons...
             for &(button_id, pressed) in &frame.buttons {
@@ -457,9 +524,7 @@ impl TabletState {
         // Emit frame. This may be an empty frame if above was None, that's alright!
         self.events.push(raw_events::Event::Tool {
             tool: tool.clone(),
-            event: raw_events::ToolEvent::Frame(Some(FrameTimestamp(
-                std::time::Duration::from_millis(u64::from(millis)),
-            ))),
+            event: raw_events::ToolEvent::Frame(Some(time)),
         });
 
         if clear {
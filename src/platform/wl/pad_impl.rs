@@ -68,6 +68,10 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_v2::ZwpTabletPadV2, ()> for TabletState
                 });
             }
             Event::Enter { tablet, .. } => {
+                let pad_id = pad.id();
+                if let Some(pad) = this.pads.iter_mut().find(|p| HasWlId::id(*p) == &pad_id) {
+                    pad.tablet = Some(tablet.id().into());
+                }
                 this.events.push(raw_events::Event::Pad {
                     pad: pad.id(),
                     event: raw_events::PadEvent::Enter {
@@ -76,6 +80,10 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_v2::ZwpTabletPadV2, ()> for TabletState
                 });
             }
             Event::Leave { .. } => {
+                let pad_id = pad.id();
+                if let Some(pad) = this.pads.iter_mut().find(|p| HasWlId::id(*p) == &pad_id) {
+                    pad.tablet = None;
+                }
                 this.events.push(raw_events::Event::Pad {
                     pad: pad.id(),
                     event: raw_events::PadEvent::Exit,
@@ -139,6 +147,7 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_group_v2::ZwpTabletPadGroupV2, ()> for T
                 ctor.rings.push(Ring {
                     granularity: None,
                     internal_id: ring.id().into(),
+                    current_angle: None,
                 });
             }
             wl_tablet::zwp_tablet_pad_group_v2::Event::Strip { strip } => {
@@ -182,6 +191,16 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_group_v2::ZwpTabletPadGroupV2, ()> for T
                 ..
             } => {
                 let Some(pad_id) = pad_id else { return };
+                // Remember the mode, for users who want to query it outside of the event stream.
+                let group_id = group.id();
+                if let Some(group) = this
+                    .pads
+                    .iter_mut()
+                    .find(|p| HasWlId::id(*p) == &pad_id)
+                    .and_then(|pad| pad.groups.iter_mut().find(|g| HasWlId::id(*g) == &group_id))
+                {
+                    group.current_mode = Some(mode);
+                }
                 this.events.push(raw_events::Event::Pad {
                     pad: pad_id,
                     event: raw_events::PadEvent::Group {
@@ -218,6 +237,14 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_ring_v2::ZwpTabletPadRingV2, ()> for Tab
         let Some(pad) = this.group_associations.get(&group).cloned() else {
             return;
         };
+        // Mode switches arrive in order with ring events, so the group's current mode
+        // is accurate for this event.
+        let mode = this
+            .pads
+            .iter()
+            .find(|p| HasWlId::id(*p) == &pad)
+            .and_then(|pad| pad.groups.iter().find(|g| HasWlId::id(*g) == &group))
+            .and_then(|group| group.current_mode);
         #[allow(clippy::match_same_arms)]
         match event {
             #[allow(clippy::cast_possible_truncation)]
@@ -227,12 +254,24 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_ring_v2::ZwpTabletPadRingV2, ()> for Tab
                 }
                 let degrees = degrees as f32;
                 let radians = degrees.to_radians();
+                // Remember the angle, for users who want to query it outside of the event stream.
+                let ring_id = ring.id();
+                if let Some(ring) = this
+                    .pads
+                    .iter_mut()
+                    .find(|p| HasWlId::id(*p) == &pad)
+                    .and_then(|pad| pad.groups.iter_mut().find(|g| HasWlId::id(*g) == &group))
+                    .and_then(|group| group.rings.iter_mut().find(|r| HasWlId::id(*r) == &ring_id))
+                {
+                    ring.current_angle = Some(radians);
+                }
                 this.events.push(raw_events::Event::Pad {
                     pad,
                     event: raw_events::PadEvent::Group {
                         group,
                         event: raw_events::PadGroupEvent::Ring {
                             ring: ring.id(),
+                            mode,
                             event: crate::events::TouchStripEvent::Pose(radians),
                         },
                     },
@@ -253,6 +292,7 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_ring_v2::ZwpTabletPadRingV2, ()> for Tab
                         group,
                         event: raw_events::PadGroupEvent::Ring {
                             ring: ring.id(),
+                            mode,
                             event: crate::events::TouchStripEvent::Source(source),
                         },
                     },
@@ -265,6 +305,7 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_ring_v2::ZwpTabletPadRingV2, ()> for Tab
                         group,
                         event: raw_events::PadGroupEvent::Ring {
                             ring: ring.id(),
+                            mode,
                             event: crate::events::TouchStripEvent::Up,
                         },
                     },
@@ -277,6 +318,7 @@ impl Dispatch<wl_tablet::zwp_tablet_pad_ring_v2::ZwpTabletPadRingV2, ()> for Tab
                         group,
                         event: raw_events::PadGroupEvent::Ring {
                             ring: ring.id(),
+                            mode,
                             event: crate::events::TouchStripEvent::Frame(Some(FrameTimestamp(
                                 std::time::Duration::from_millis(u64::from(time)),
                             ))),
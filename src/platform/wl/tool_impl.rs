@@ -40,10 +40,10 @@ impl Dispatch<wl_tablet::zwp_tablet_tool_v2::ZwpTabletToolV2, ()> for TabletStat
                         ctor.axes.slider = Some(axis::SliderInfo::default());
                     }
                     Capability::Tilt => {
-                        ctor.axes.tilt = Some(axis::Info {
+                        ctor.axes.tilt = Some(this.tilt_unit.convert_radians_info(axis::Info {
                             limits: Some((-std::f32::consts::PI..=std::f32::consts::PI).into()),
                             granularity: None,
-                        });
+                        }));
                     }
                     Capability::Wheel => {
                         ctor.axes.wheel = Some(axis::CircularInfo::default());
@@ -125,8 +125,9 @@ impl Dispatch<wl_tablet::zwp_tablet_tool_v2::ZwpTabletToolV2, ()> for TabletStat
             }
             #[allow(clippy::cast_possible_truncation)]
             Event::Tilt { tilt_x, tilt_y } => {
-                let tilt_x = (tilt_x as f32).to_radians();
-                let tilt_y = (tilt_y as f32).to_radians();
+                // Reported in degrees.
+                let tilt_x = this.tilt_unit.convert_degrees(tilt_x as f32);
+                let tilt_y = this.tilt_unit.convert_degrees(tilt_y as f32);
                 this.frame_in_progress(tool.id()).tilt = Some([tilt_x, tilt_y]);
             }
             Event::Pressure { pressure } => {
@@ -9,18 +9,89 @@
 
 crate::util::macro_bits::impl_get_id!(ID for Tablet);
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
 pub struct UsbId {
     /// Vendor ID
     pub vid: u16,
     /// Product ID
     pub pid: u16,
 }
+// Formatted like `lsusb` does, for easy lookup.
+impl std::fmt::Display for UsbId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04x}:{:04x}", self.vid, self.pid)
+    }
+}
+impl std::fmt::Debug for UsbId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "UsbId({self})")
+    }
+}
 
 /// See [module level docs](`crate::tablet`) for details.
-#[derive(Debug)]
 pub struct Tablet {
     pub(crate) internal_id: crate::InternalID,
     pub name: Option<String>,
     pub usb_id: Option<UsbId>,
+    pub(crate) emulated: bool,
+}
+// Manual impl to keep platform internals out of logs.
+impl std::fmt::Debug for Tablet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tablet")
+            .field("id", &self.id())
+            .field("name", &self.name)
+            .field("usb_id", &self.usb_id)
+            .field("emulated", &self.emulated)
+            .finish_non_exhaustive()
+    }
+}
+impl Tablet {
+    /// Query whether this tablet is a placeholder synthesized by the platform rather than real hardware, such as the
+    /// tablet backing mouse emulation (see [`Builder::emulate_tool_from_mouse`](crate::Builder::emulate_tool_from_mouse)).
+    /// Useful for hiding or labeling it in device lists.
+    ///
+    /// # Platform support
+    /// * Windows Ink. Wayland never reports emulated tablets.
+    #[must_use]
+    pub fn is_emulated(&self) -> bool {
+        self.emulated
+    }
+    /// Iterate over the pads currently associated with this tablet.
+    ///
+    /// # Platform support
+    /// * Wayland only.
+    pub fn pads<'a>(
+        &self,
+        manager: &'a crate::Manager,
+    ) -> impl Iterator<Item = &'a crate::pad::Pad> + 'a {
+        let id = self.internal_id.clone();
+        manager
+            .pads()
+            .iter()
+            .filter(move |pad| pad.tablet.as_ref() == Some(&id))
+    }
+    /// Iterate over the tools currently [in proximity](crate::events::ToolEvent::In) of this tablet.
+    ///
+    /// Tools are not permanently bound to a tablet and may roam between several, so tools that are out of
+    /// proximity are not associated with any tablet.
+    pub fn tools<'a>(
+        &self,
+        manager: &'a crate::Manager,
+    ) -> impl Iterator<Item = &'a crate::tool::Tool> + 'a {
+        let id = self.internal_id.clone();
+        manager.tools().iter().filter(move |tool| {
+            manager
+                .tool_state(tool)
+                .is_some_and(|state| state.tablet.as_ref() == Some(&id))
+        })
+    }
+    /// Count the tools currently [in proximity](crate::events::ToolEvent::In) of this tablet. See [`Tablet::tools`].
+    ///
+    /// Tablets are reported as soon as they're connected, regardless of tools, so zero can be used to show a
+    /// "no pen detected" state.
+    #[must_use]
+    pub fn tool_count(&self, manager: &crate::Manager) -> usize {
+        self.tools(manager).count()
+    }
 }
@@ -64,7 +64,6 @@ pub enum Type {
 }
 
 /// Description of the capabilities of a tool.
-#[derive(Debug)]
 pub struct Tool {
     /// Platform internal ID.
     pub(crate) internal_id: crate::InternalID,
@@ -88,3 +87,139 @@ pub struct Tool {
 }
 
 crate::util::macro_bits::impl_get_id!(ID for Tool);
+impl Tool {
+    /// The type of the tool, assuming [`Type::Pen`] if unknown, as that's the overwhelmingly common case.
+    #[must_use]
+    pub fn kind(&self) -> Type {
+        self.tool_type.unwrap_or(Type::Pen)
+    }
+    /// Whether this tool is known to be an [eraser](Type::Eraser).
+    #[must_use]
+    pub fn is_eraser(&self) -> bool {
+        self.tool_type == Some(Type::Eraser)
+    }
+    /// Whether this tool is known to be a puck resting on the surface, [`Type::Mouse`] or [`Type::Lens`], which are
+    /// better suited to pointing than drawing.
+    #[must_use]
+    pub fn is_pointer_like(&self) -> bool {
+        matches!(self.tool_type, Some(Type::Mouse | Type::Lens))
+    }
+}
+// Manual impl to keep platform internals out of logs, and summarize the rather large axis report.
+impl Debug for Tool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tool")
+            .field("id", &self.id())
+            .field("name", &self.name)
+            .field("tool_type", &self.tool_type)
+            .field("hardware_id", &self.hardware_id)
+            .field("wacom_id", &self.wacom_id)
+            .field("axes", &self.axes.available())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Live state of a tool that is currently [in proximity](crate::events::ToolEvent::In), accumulated from its events.
+/// See [`Manager::tool_state`](crate::Manager::tool_state).
+///
+/// This is discarded when the tool goes [`Out`](crate::events::ToolEvent::Out), so no state lingers between interactions.
+#[derive(Clone, Debug, Default)]
+#[allow(clippy::module_name_repetitions)]
+pub struct ToolState {
+    /// Sorted and deduplicated.
+    buttons: Vec<ButtonID>,
+    /// The tablet given by the last `In`.
+    pub(crate) tablet: Option<crate::InternalID>,
+    /// Whether the tool is between a `Down` and an `Up`.
+    pub(crate) down: bool,
+    /// Position from a `Pose` in the current, not-yet-finished frame.
+    pending_position: Option<[f32; 2]>,
+    /// Position and time of the last timestamped frame with a pose.
+    last_sample: Option<([f32; 2], crate::events::FrameTimestamp)>,
+    velocity: Option<[f32; 2]>,
+    /// Times of up to the last [`ToolState::REPORT_RATE_WINDOW`] timestamped frames with a pose, oldest first.
+    sample_times: std::collections::VecDeque<crate::events::FrameTimestamp>,
+}
+impl ToolState {
+    /// How many of the most recent samples [`ToolState::report_hz`] is estimated over.
+    const REPORT_RATE_WINDOW: usize = 32;
+    /// The buttons currently held on this tool, in the arbitrary but stable order of [`ButtonID`].
+    #[must_use]
+    pub fn pressed_buttons(&self) -> &[ButtonID] {
+        &self.buttons
+    }
+    /// Query whether the given button is currently held.
+    #[must_use]
+    pub fn is_pressed(&self, button: ButtonID) -> bool {
+        self.buttons.binary_search(&button).is_ok()
+    }
+    /// Estimated velocity of the tool, in logical pixels per second, from the positions of the last two timestamped frames.
+    ///
+    /// This is a plain difference between the two samples with no smoothing applied, so it is as noisy as the
+    /// hardware is. `None` until two timestamped frames with a pose have been seen since the tool came [`In`](crate::events::ToolEvent::In),
+    /// or if the platform does not report frame timestamps.
+    #[must_use]
+    pub fn velocity(&self) -> Option<[f32; 2]> {
+        self.velocity
+    }
+    /// Estimated rate at which the tool reports poses, in Hertz, averaged over a sliding window of recent frames.
+    ///
+    /// Useful for diagnostics, or for choosing a [prediction](crate::Builder::predict_motion) window. `None` under the
+    /// same conditions as [`ToolState::velocity`]. Tools may only report while moving, so a tool held still reads low.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn report_hz(&self) -> Option<f32> {
+        let (first, last) = (self.sample_times.front()?, self.sample_times.back()?);
+        let elapsed = last.duration_since(*first).as_secs_f32();
+        // Intervals, not samples, per second. Precision loss is fine, the window is tiny.
+        (elapsed > 0.0).then(|| (self.sample_times.len() - 1) as f32 / elapsed)
+    }
+    /// Accumulate the next event of this tool.
+    pub(crate) fn update(&mut self, event: &crate::events::raw::ToolEvent<crate::InternalID>) {
+        use crate::events::raw::ToolEvent;
+        match *event {
+            ToolEvent::In { ref tablet } => self.tablet = Some(tablet.clone()),
+            ToolEvent::Down => self.down = true,
+            ToolEvent::Up => self.down = false,
+            ToolEvent::Pose(ref pose) => self.pending_position = Some(pose.position),
+            ToolEvent::Frame(time) => {
+                let Some(position) = self.pending_position.take() else {
+                    // Frame without motion, keep the last estimate.
+                    return;
+                };
+                let Some(time) = time else {
+                    // Untimed, can't tell how fast this was. Keep the last estimate - these may be sprinkled between
+                    // timed frames, such as the repeated poses of `Builder::pose_on_button`.
+                    return;
+                };
+                if let Some((last_position, last_time)) = self.last_sample {
+                    let elapsed = time.duration_since(last_time).as_secs_f32();
+                    // Duplicate timestamps give no notion of velocity, keep the last estimate.
+                    if elapsed > 0.0 {
+                        self.velocity = Some([
+                            (position[0] - last_position[0]) / elapsed,
+                            (position[1] - last_position[1]) / elapsed,
+                        ]);
+                    }
+                }
+                self.last_sample = Some((position, time));
+                if self.sample_times.len() == Self::REPORT_RATE_WINDOW {
+                    self.sample_times.pop_front();
+                }
+                self.sample_times.push_back(time);
+            }
+            ToolEvent::Button { button_id, pressed } => {
+                let button_id = ButtonID(button_id);
+                match (self.buttons.binary_search(&button_id), pressed) {
+                    (Err(idx), true) => self.buttons.insert(idx, button_id),
+                    (Ok(idx), false) => {
+                        self.buttons.remove(idx);
+                    }
+                    // Redundant press or release.
+                    _ => (),
+                }
+            }
+            _ => (),
+        }
+    }
+}
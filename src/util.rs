@@ -12,6 +12,7 @@ pub struct NicheF32(f32);
 impl NicheF32 {
     pub const NONE: NicheF32 = NicheF32(f32::NAN);
     pub const ZERO: NicheF32 = NicheF32(0.0);
+    pub const ONE: NicheF32 = NicheF32(1.0);
     /// Wrap a float in this niche, `NaN` coercing to `None`.
     // Not pub cause it might be a footgun lol
     #[must_use]